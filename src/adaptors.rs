@@ -1,61 +1,133 @@
 use super::*;
 
+mod array_chunks;
+mod array_windows;
+mod at_least;
+mod at_most;
+mod cartesian_product;
 mod chain;
+mod chunk_by;
+#[cfg(feature = "alloc")]
+mod chunks;
+#[cfg(feature = "alloc")]
+mod chunks_exact;
 mod cloned;
+mod coalesce;
+#[cfg(feature = "alloc")]
+mod combinations;
+#[cfg(feature = "alloc")]
+mod combinations_with_replacement;
 mod copied;
 mod cycle;
+mod dedup_by;
+mod dedup_by_key;
+mod dedup_with_count;
+mod duplicates_by;
 mod enumerate;
+mod expect_len;
 mod filter;
 mod filter_map;
 mod flatten;
 mod from_fn;
 mod fuse;
+#[cfg(feature = "alloc")]
+mod hash_join;
 mod inspect;
+mod interleave;
+mod interleave_shortest;
+mod intersperse;
 mod into_results;
+#[cfg(feature = "alloc")]
+mod lazy_buffer;
 mod map;
 mod map_err;
 mod map_err_mut;
 mod map_while;
+mod merge_join_by;
 mod once_with;
 mod peekable;
+#[cfg(feature = "alloc")]
+mod permutations;
+#[cfg(feature = "alloc")]
+mod powerset;
 mod repeat_with;
 mod rev;
 mod rev_mut;
 mod scan;
 mod skip;
 mod skip_while;
+mod split;
 mod step_by;
 mod successors;
 mod take;
 mod take_while;
+mod tuple_windows;
+mod unique_by;
 mod zip;
 
+pub use array_chunks::ArrayChunks;
+pub use array_windows::ArrayWindows;
+pub use at_least::AtLeast;
+pub use at_most::AtMost;
+pub use cartesian_product::CartesianProduct;
 pub use chain::Chain;
+pub use chunk_by::ChunkBy;
+#[cfg(feature = "alloc")]
+pub use chunk_by::GroupByKey;
+#[cfg(feature = "alloc")]
+pub use chunks::Chunks;
+#[cfg(feature = "alloc")]
+pub use chunks_exact::ChunksExact;
 pub use cloned::Cloned;
+pub use coalesce::Coalesce;
+#[cfg(feature = "alloc")]
+pub use combinations::Combinations;
+#[cfg(feature = "alloc")]
+pub use combinations_with_replacement::CombinationsWithReplacement;
 pub use copied::Copied;
 pub use cycle::Cycle;
+pub use dedup_by::{Dedup, DedupBy};
+pub use dedup_by_key::DedupByKey;
+pub use dedup_with_count::DedupWithCount;
+pub use duplicates_by::{Duplicates, DuplicatesBy};
 pub use enumerate::Enumerate;
+pub use expect_len::ExpectLen;
 pub use filter::Filter;
 pub use filter_map::FilterMap;
 pub use flatten::Flatten;
 pub use from_fn::{from_fn, FromFn};
 pub use fuse::Fuse;
+#[cfg(feature = "alloc")]
+pub use hash_join::HashJoin;
 pub use inspect::Inspect;
+pub use interleave::Interleave;
+pub use interleave_shortest::InterleaveShortest;
+pub use intersperse::{Intersperse, IntersperseWith};
 pub use into_results::IntoResults;
 pub use map::Map;
 pub use map_err::MapErr;
 pub use map_err_mut::MapErrMut;
 pub use map_while::MapWhile;
+pub use merge_join_by::MergeJoinBy;
 pub use once_with::{once_with, OnceWith};
 pub use peekable::Peekable;
+#[cfg(feature = "alloc")]
+pub use permutations::Permutations;
+#[cfg(feature = "alloc")]
+pub use powerset::Powerset;
 pub use repeat_with::{repeat_with, RepeatWith};
 pub use rev::Rev;
 pub use rev_mut::RevMut;
 pub use scan::Scan;
 pub use skip::Skip;
 pub use skip_while::SkipWhile;
+pub use split::SplitFold;
+#[cfg(feature = "alloc")]
+pub use split::{RSplit, Split};
 pub use step_by::StepBy;
 pub use successors::{successors, Successors};
 pub use take::Take;
 pub use take_while::TakeWhile;
+pub use tuple_windows::{HomogeneousTuple, TupleWindows};
+pub use unique_by::{Unique, UniqueBy};
 pub use zip::Zip;
@@ -0,0 +1,61 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<I, const N: usize>
+where I: TryIterator
+{
+    iter: I,
+    buffer: ArrayBuffer<I::Item, N>,
+}
+
+impl<I, const N: usize> ArrayChunks<I, N>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self { iter, buffer: ArrayBuffer::new() }
+    }
+
+    // the items that have been read but do not (yet) make up a full chunk
+    pub fn remainder(&self) -> &[I::Item] {
+        self.buffer.as_slice()
+    }
+}
+
+impl<I, const N: usize> TryIterator for ArrayChunks<I, N>
+where I: TryIterator
+{
+    type Item = [I::Item; N];
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::div(size_hint::add(self.iter.size_hint(), self.buffer.len()), N)
+    }
+
+    // items are moved into the buffer as soon as they are read, so an error
+    // never loses the part of a chunk that was already collected
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let buffer = &mut self.buffer;
+        self.iter.try_fold(acc, |acc, x| {
+            buffer.push(x);
+            match buffer.take_array() {
+                None => Try::from_ok(acc),
+                Some(chunk) => f(acc, chunk),
+            }
+        })
+    }
+}
+
+impl<I, const N: usize> ExactSizeTryIterator for ArrayChunks<I, N> where I: ExactSizeTryIterator {}
+
+impl<I, const N: usize> FusedTryIterator for ArrayChunks<I, N> where I: FusedTryIterator {}
@@ -0,0 +1,72 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindows<I, const N: usize>
+where I: TryIterator
+{
+    iter: I,
+    buffer: ArrayBuffer<I::Item, N>,
+}
+
+impl<I, const N: usize> ArrayWindows<I, N>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "window size must be non-zero");
+        Self { iter, buffer: ArrayBuffer::new() }
+    }
+}
+
+impl<I, const N: usize> TryIterator for ArrayWindows<I, N>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::sub(size_hint::add(self.iter.size_hint(), self.buffer.len()), N - 1)
+    }
+
+    // in between windows the buffer holds the last `N - 1` items
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let buffer = &mut self.buffer;
+        self.iter.try_fold(acc, |acc, x| {
+            buffer.push(x);
+            if !buffer.is_full() {
+                return Try::from_ok(acc);
+            }
+            let window = buffer.clone().take_array();
+            buffer.pop_front();
+            match window {
+                None => unreachable!(),
+                Some(window) => f(acc, window),
+            }
+        })
+    }
+}
+
+impl<I, const N: usize> ExactSizeTryIterator for ArrayWindows<I, N>
+where
+    I: ExactSizeTryIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> FusedTryIterator for ArrayWindows<I, N>
+where
+    I: FusedTryIterator,
+    I::Item: Clone,
+{
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AtLeast<I> {
+    iter: I,
+    n: usize,
+    count: usize,
+    done: bool,
+}
+
+impl<I> AtLeast<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n, count: 0, done: false }
+    }
+}
+
+impl<I> TryIterator for AtLeast<I>
+where I: TryIterator
+{
+    type Item = I::Item;
+    type Error = LengthError<I::Error>;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    // a source that comes up short fails instead of yielding more items, so
+    // the items are exactly those of the underlying iterator
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { size_hint::ZERO } else { self.iter.size_hint() }
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        if self.done {
+            return Try::from_ok(acc);
+        }
+
+        let count = &mut self.count;
+        let acc = self.iter.map_err_mut(LengthError::IterError).try_fold(acc, |acc, x| {
+            *count += 1;
+            f(acc, x)
+        })?;
+
+        self.done = true;
+        if self.count < self.n {
+            let mismatch = LengthMismatch { expected: self.n, actual: self.count };
+            Err(LengthError::Mismatch(mismatch))?;
+        }
+        Try::from_ok(acc)
+    }
+}
+
+impl<I> ExactSizeTryIterator for AtLeast<I> where I: ExactSizeTryIterator {}
+
+impl<I> FusedTryIterator for AtLeast<I> where I: TryIterator {}
@@ -0,0 +1,66 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AtMost<I> {
+    iter: I,
+    n: usize,
+    count: usize,
+    done: bool,
+}
+
+impl<I> AtMost<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n, count: 0, done: false }
+    }
+}
+
+impl<I> TryIterator for AtMost<I>
+where I: TryIterator
+{
+    type Item = I::Item;
+    type Error = LengthError<I::Error>;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            size_hint::ZERO
+        } else {
+            size_hint::min(self.iter.size_hint(), self.n - self.count)
+        }
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        if self.done {
+            return Try::from_ok(acc);
+        }
+
+        let n = self.n;
+        let count = &mut self.count;
+        let done = &mut self.done;
+        let acc = self.iter.map_err_mut(LengthError::IterError).try_fold(acc, |acc, x| {
+            if *count == n {
+                *done = true;
+                let mismatch = LengthMismatch { expected: n, actual: n + 1 };
+                return Try::from_error(LengthError::Mismatch(mismatch).into());
+            }
+            *count += 1;
+            f(acc, x)
+        })?;
+
+        self.done = true;
+        Try::from_ok(acc)
+    }
+}
+
+impl<I> ExactSizeTryIterator for AtMost<I> where I: ExactSizeTryIterator {}
+
+impl<I> FusedTryIterator for AtMost<I> where I: TryIterator {}
@@ -0,0 +1,94 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CartesianProduct<A, B>
+where A: TryIterator
+{
+    a: A,
+    a_cur: Option<A::Item>,
+    b: B,
+    b_orig: B,
+    paired: bool,
+}
+
+impl<A, B> CartesianProduct<A, B>
+where
+    A: TryIterator,
+    B: Clone,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        let b_orig = b.clone();
+        Self { a, a_cur: None, b, b_orig, paired: false }
+    }
+}
+
+impl<A, B> TryIterator for CartesianProduct<A, B>
+where
+    A: TryIterator,
+    A::Item: Clone,
+    B: TryIterator + Clone,
+    A::Error: From<B::Error>,
+{
+    type Item = (A::Item, B::Item);
+    type Error = A::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // an error from `a` is yielded in place of a whole pass over `b`, so
+        // each item `a` has left only counts for one at the least
+        let (lower, upper) = size_hint::mul(self.a.size_hint(), self.b_orig.size_hint());
+        let rest = (cmp::min(lower, self.a.size_hint().0), upper);
+        match self.a_cur {
+            None => rest,
+            Some(_) => size_hint::add(rest, self.b.size_hint()),
+        }
+    }
+
+    // the current item of `a` is kept until `b` runs out, so an error from
+    // either side resumes at the same pair. if `b` turns out to be empty, the
+    // iterator stops without draining `a`
+    fn try_fold<Acc, F, R>(&mut self, mut acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        loop {
+            if self.a_cur.is_none() {
+                match self.a.next()? {
+                    None => return Try::from_ok(acc),
+                    x => self.a_cur = x,
+                }
+            }
+
+            let x = &self.a_cur;
+            let paired = &mut self.paired;
+            acc = self.b.map_err_mut(A::Error::from).try_fold(acc, |acc, y| {
+                *paired = true;
+                match x {
+                    None => unreachable!(),
+                    Some(x) => f(acc, (x.clone(), y)),
+                }
+            })?;
+
+            self.b = self.b_orig.clone();
+            if !mem::replace(&mut self.paired, false) {
+                return Try::from_ok(acc);
+            }
+            self.a_cur = None;
+        }
+    }
+}
+
+impl<A, B> FusedTryIterator for CartesianProduct<A, B>
+where
+    A: FusedTryIterator,
+    A::Item: Clone,
+    B: FusedTryIterator + Clone,
+    A::Error: From<B::Error>,
+{
+}
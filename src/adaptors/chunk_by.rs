@@ -0,0 +1,131 @@
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+pub type GroupByKey<I, K, F> = ChunkBy<
+    I,
+    K,
+    F,
+    fn() -> Vec<<I as TryIterator>::Item>,
+    fn(Vec<<I as TryIterator>::Item>, <I as TryIterator>::Item) -> Vec<<I as TryIterator>::Item>,
+>;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkBy<I, K, F, N, G>
+where N: FnOnce<()>
+{
+    iter: I,
+    f: F,
+    init: N,
+    g: G,
+    current: Option<(K, N::Output)>,
+    // whether `current` is complete, because the key function failed
+    ended: bool,
+}
+
+impl<I, K, F, N, G> ChunkBy<I, K, F, N, G>
+where N: FnOnce<()>
+{
+    pub(crate) fn new(iter: I, f: F, init: N, g: G) -> Self {
+        Self { iter, f, init, g, current: None, ended: false }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, K, F> GroupByKey<I, K, F>
+where I: TryIterator
+{
+    pub(crate) fn group_by_key(iter: I, f: F) -> Self {
+        Self::new(iter, f, Vec::new, push)
+    }
+}
+
+impl<I, K, F, N, G, R, B> TryIterator for ChunkBy<I, K, F, N, G>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = K>,
+    R::Error: From<I::Error>,
+    K: PartialEq,
+    N: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    type Item = (K, B);
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add(self.iter.size_hint(), self.current.is_some() as usize);
+        (cmp::min(lower, 1), upper)
+    }
+
+    // a group is only yielded once an item with a different key shows up or
+    // the underlying iterator runs out. errors from the underlying iterator
+    // leave the group that is being built in place, so it is never yielded
+    // with only part of its items. if the key function fails, the item is
+    // dropped and the group before it ends there, rather than being merged
+    // with the items after it
+    fn try_fold<Acc, H, Q>(&mut self, mut acc: Acc, mut h: H) -> Q
+    where
+        H: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        if mem::take(&mut self.ended) {
+            if let Some(group) = self.current.take() {
+                acc = h(acc, group)?;
+            }
+        }
+
+        let f = &mut self.f;
+        let init = &mut self.init;
+        let g = &mut self.g;
+        let current = &mut self.current;
+        let ended = &mut self.ended;
+        let acc = self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            let key = match f(&x).into_result() {
+                Ok(key) => key,
+                Err(e) => {
+                    *ended = true;
+                    return Try::from_error(Q::Error::from(e));
+                }
+            };
+            match current.take() {
+                Some((prev_key, chunk)) if prev_key == key => {
+                    *current = Some((prev_key, g(chunk, x)));
+                    Try::from_ok(acc)
+                }
+                prev => {
+                    *current = Some((key, g(init(), x)));
+                    match prev {
+                        None => Try::from_ok(acc),
+                        Some(group) => h(acc, group),
+                    }
+                }
+            }
+        })?;
+
+        match self.current.take() {
+            None => Try::from_ok(acc),
+            Some(group) => h(acc, group),
+        }
+    }
+}
+
+impl<I, K, F, N, G, R, B> FusedTryIterator for ChunkBy<I, K, F, N, G>
+where
+    I: FusedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = K>,
+    R::Error: From<I::Error>,
+    K: PartialEq,
+    N: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I>
+where I: TryIterator
+{
+    iter: Fuse<I>,
+    size: usize,
+    front: Vec<I::Item>,
+    // the items of a partially read chunk at the back, in reverse order
+    back: Vec<I::Item>,
+}
+
+impl<I> Chunks<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { iter: Fuse::new(iter), size, front: Vec::new(), back: Vec::new() }
+    }
+}
+
+impl<I> TryIterator for Chunks<I>
+where I: TryIterator
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    // an error leaves the items of the chunk that is being read in place, so
+    // the next call continues that same chunk
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while self.front.len() < self.size {
+            match self.iter.next()? {
+                Some(x) => self.front.push(x),
+                None => match self.back.pop() {
+                    Some(x) => self.front.push(x),
+                    None => break,
+                },
+            }
+        }
+        Ok(match self.front.is_empty() {
+            true => None,
+            false => Some(mem::replace(&mut self.front, Vec::with_capacity(self.size))),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = size_hint::add(self.iter.size_hint(), self.front.len() + self.back.len());
+        size_hint::div_ceil(len, self.size)
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let size = self.size;
+        let front = &mut self.front;
+        let acc = self.iter.try_fold(acc, |acc, x| {
+            front.push(x);
+            match front.len() < size {
+                true => Try::from_ok(acc),
+                false => f(acc, mem::replace(front, Vec::with_capacity(size))),
+            }
+        })?;
+
+        match self.next()? {
+            None => Try::from_ok(acc),
+            Some(chunk) => f(acc, chunk),
+        }
+    }
+}
+
+impl<I> DoubleEndedTryIterator for Chunks<I>
+where I: DoubleEndedTryIterator + ExactSizeTryIterator
+{
+    // the last chunk is the one that is left over after splitting the
+    // remaining items into chunks from the front, so its size follows from
+    // the number of remaining items
+    fn next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let len = self.front.len() + self.iter.len() + self.back.len();
+        if len == 0 {
+            return Ok(None);
+        }
+        let size = (len - 1) % self.size + 1;
+        while self.back.len() < size {
+            match self.iter.next_back()? {
+                Some(x) => self.back.push(x),
+                None => match self.front.pop() {
+                    Some(x) => self.back.push(x),
+                    None => break,
+                },
+            }
+        }
+        let mut chunk = mem::take(&mut self.back);
+        chunk.reverse();
+        Ok(Some(chunk))
+    }
+}
+
+impl<I> ExactSizeTryIterator for Chunks<I> where I: ExactSizeTryIterator {}
+
+impl<I> FusedTryIterator for Chunks<I> where I: TryIterator {}
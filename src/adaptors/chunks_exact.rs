@@ -0,0 +1,63 @@
+use super::*;
+
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksExact<I>
+where I: TryIterator
+{
+    iter: I,
+    size: usize,
+    buffer: Vec<I::Item>,
+}
+
+impl<I> ChunksExact<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { iter, size, buffer: Vec::with_capacity(size) }
+    }
+
+    // the items that have been read but do not (yet) make up a full chunk
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.buffer
+    }
+}
+
+impl<I> TryIterator for ChunksExact<I>
+where I: TryIterator
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::div(size_hint::add(self.iter.size_hint(), self.buffer.len()), self.size)
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let size = self.size;
+        let buffer = &mut self.buffer;
+        self.iter.try_fold(acc, |acc, x| {
+            buffer.push(x);
+            match buffer.len() < size {
+                true => Try::from_ok(acc),
+                false => f(acc, mem::replace(buffer, Vec::with_capacity(size))),
+            }
+        })
+    }
+}
+
+impl<I> ExactSizeTryIterator for ChunksExact<I> where I: ExactSizeTryIterator {}
+
+impl<I> FusedTryIterator for ChunksExact<I> where I: FusedTryIterator {}
@@ -0,0 +1,84 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Coalesce<I, F>
+where I: TryIterator
+{
+    iter: I,
+    last: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> Coalesce<I, F>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, last: None, f }
+    }
+}
+
+impl<I, F, R> TryIterator for Coalesce<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Item, I::Item) -> R,
+    R: Try<Ok = Result<I::Item, (I::Item, I::Item)>>,
+    R::Error: From<I::Error>,
+{
+    type Item = I::Item;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add(self.iter.size_hint(), self.last.is_some() as usize);
+        (cmp::min(lower, 1), upper)
+    }
+
+    // the closure takes both items by value, so when it fails neither of them
+    // is yielded and coalescing starts over from the next item
+    fn try_fold<Acc, G, Q>(&mut self, acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        let f = &mut self.f;
+        let last = &mut self.last;
+        let acc = self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            let prev = match last.take() {
+                None => {
+                    *last = Some(x);
+                    return Try::from_ok(acc);
+                }
+                Some(prev) => prev,
+            };
+            match f(prev, x)? {
+                Ok(merged) => {
+                    *last = Some(merged);
+                    Try::from_ok(acc)
+                }
+                Err((prev, x)) => {
+                    *last = Some(x);
+                    g(acc, prev)
+                }
+            }
+        })?;
+
+        match self.last.take() {
+            None => Try::from_ok(acc),
+            Some(x) => g(acc, x),
+        }
+    }
+}
+
+impl<I, F, R> FusedTryIterator for Coalesce<I, F>
+where
+    I: FusedTryIterator,
+    F: FnMut(I::Item, I::Item) -> R,
+    R: Try<Ok = Result<I::Item, (I::Item, I::Item)>>,
+    R::Error: From<I::Error>,
+{
+}
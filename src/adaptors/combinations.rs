@@ -0,0 +1,111 @@
+use super::*;
+
+use super::lazy_buffer::LazyBuffer;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Combinations<I>
+where I: TryIterator
+{
+    pool: LazyBuffer<I, I::Item>,
+    indices: Vec<usize>,
+    first: bool,
+}
+
+impl<I> Combinations<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, k: usize) -> Self {
+        Self { pool: LazyBuffer::new(iter), indices: (0..k).collect(), first: true }
+    }
+
+    pub(crate) fn k(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub(crate) fn n(&self) -> usize {
+        self.pool.len()
+    }
+
+    pub(crate) fn pool_size_hint(&self) -> (usize, Option<usize>) {
+        self.pool.size_hint()
+    }
+
+    pub(crate) fn reset(&mut self, k: usize) {
+        self.indices = (0..k).collect();
+        self.first = true;
+    }
+
+    // the number of combinations that are left if the pool has `n` items
+    pub(crate) fn remaining_for(&self, n: usize) -> Option<usize> {
+        let k = self.k();
+        if n < k {
+            Some(0)
+        } else if self.first {
+            size_hint::checked_binomial(n, k)
+        } else {
+            // combinations are produced in lexicographic order, so for every
+            // index we count the combinations that have a larger item there
+            self.indices.iter().enumerate().try_fold(0usize, |sum, (i, &x)| {
+                sum.checked_add(size_hint::checked_binomial(n - 1 - x, k - i)?)
+            })
+        }
+    }
+}
+
+impl<I> TryIterator for Combinations<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    // items are pulled from the source only when a combination needs them.
+    // the indices are only updated after pulling succeeded, so an error
+    // leaves the iterator at the same position
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let k = self.k();
+        if self.first {
+            self.pool.prefill(k)?;
+            if self.pool.len() < k {
+                return Ok(None);
+            }
+            self.first = false;
+        } else if k == 0 {
+            return Ok(None);
+        } else {
+            let mut i = k - 1;
+            if self.indices[i] == self.pool.len() - 1 {
+                self.pool.get_next()?;
+            }
+            while self.indices[i] == i + self.pool.len() - k {
+                if i == 0 {
+                    return Ok(None);
+                }
+                i -= 1;
+            }
+            self.indices[i] += 1;
+            for j in i + 1..k {
+                self.indices[j] = self.indices[j - 1] + 1;
+            }
+        }
+        Ok(Some(self.pool.get_at(&self.indices)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.pool.size_hint();
+        (
+            size_hint::lower_with_errors(self.n(), lower, |n| self.remaining_for(n)),
+            upper.and_then(|n| self.remaining_for(n)),
+        )
+    }
+}
+
+impl<I> FusedTryIterator for Combinations<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+}
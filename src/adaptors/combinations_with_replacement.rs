@@ -0,0 +1,94 @@
+use super::*;
+
+use super::lazy_buffer::LazyBuffer;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CombinationsWithReplacement<I>
+where I: TryIterator
+{
+    pool: LazyBuffer<I, I::Item>,
+    indices: Vec<usize>,
+    first: bool,
+}
+
+impl<I> CombinationsWithReplacement<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, k: usize) -> Self {
+        Self { pool: LazyBuffer::new(iter), indices: alloc::vec![0; k], first: true }
+    }
+
+    fn remaining_for(&self, n: usize) -> Option<usize> {
+        // the number of multisets of size `k` out of `n` items
+        let count = |n: usize, k: usize| {
+            let positions = match n {
+                0 => k.saturating_sub(1),
+                _ => (n - 1).checked_add(k)?,
+            };
+            size_hint::checked_binomial(positions, k)
+        };
+        let k = self.indices.len();
+        if self.first {
+            count(n, k)
+        } else {
+            self.indices
+                .iter()
+                .enumerate()
+                .try_fold(0usize, |sum, (i, &x)| sum.checked_add(count(n - 1 - x, k - i)?))
+        }
+    }
+}
+
+impl<I> TryIterator for CombinationsWithReplacement<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    // same as `Combinations`, the source is only read when the next
+    // combination needs a new item
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.first {
+            if !self.indices.is_empty() && self.pool.len() == 0 && !self.pool.get_next()? {
+                return Ok(None);
+            }
+            self.first = false;
+            return Ok(Some(self.pool.get_at(&self.indices)));
+        }
+
+        if self.indices.is_empty() {
+            return Ok(None);
+        }
+        self.pool.get_next()?;
+        let last = self.pool.len().saturating_sub(1);
+        match self.indices.iter().rposition(|&x| x < last) {
+            None => Ok(None),
+            Some(i) => {
+                let x = self.indices[i] + 1;
+                for index in &mut self.indices[i..] {
+                    *index = x;
+                }
+                Ok(Some(self.pool.get_at(&self.indices)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.pool.size_hint();
+        (
+            size_hint::lower_with_errors(self.pool.len(), lower, |n| self.remaining_for(n)),
+            upper.and_then(|n| self.remaining_for(n)),
+        )
+    }
+}
+
+impl<I> FusedTryIterator for CombinationsWithReplacement<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+}
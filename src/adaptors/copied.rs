@@ -46,7 +46,7 @@ where
     }
 
     fn last(self) -> Result<Option<Self::Item>, Self::Error> {
-        try { self.iter.last()?.map(|&x| x) }
+        try { self.iter.last()?.copied() }
     }
 }
 
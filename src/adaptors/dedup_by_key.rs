@@ -0,0 +1,76 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupByKey<I, F>
+where I: TryIterator
+{
+    iter: I,
+    last: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> DedupByKey<I, F>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, last: None, f }
+    }
+}
+
+impl<I, F, R, K> TryIterator for DedupByKey<I, F>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = K>,
+    R::Error: From<I::Error>,
+    K: PartialEq,
+{
+    type Item = I::Item;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add(self.iter.size_hint(), self.last.is_some() as usize);
+        (cmp::min(lower, 1), upper)
+    }
+
+    fn try_fold<Acc, G, Q>(&mut self, acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        let f = &mut self.f;
+        let last = &mut self.last;
+        let acc = self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            if let Some(ref prev) = *last {
+                if f(prev)? == f(&x)? {
+                    return Try::from_ok(acc);
+                }
+            }
+            match last.replace(x) {
+                None => Try::from_ok(acc),
+                Some(prev) => g(acc, prev),
+            }
+        })?;
+
+        match self.last.take() {
+            None => Try::from_ok(acc),
+            Some(x) => g(acc, x),
+        }
+    }
+}
+
+impl<I, F, R, K> FusedTryIterator for DedupByKey<I, F>
+where
+    I: FusedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = K>,
+    R::Error: From<I::Error>,
+    K: PartialEq,
+{
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupWithCount<I>
+where I: TryIterator
+{
+    iter: I,
+    last: Option<(usize, I::Item)>,
+}
+
+impl<I> DedupWithCount<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, last: None }
+    }
+}
+
+impl<I> TryIterator for DedupWithCount<I>
+where
+    I: TryIterator,
+    I::Item: PartialEq,
+{
+    type Item = (usize, I::Item);
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = size_hint::add(self.iter.size_hint(), self.last.is_some() as usize);
+        (cmp::min(lower, 1), upper)
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let last = &mut self.last;
+        let acc = self.iter.try_fold(acc, |acc, x| match *last {
+            Some((ref mut n, ref prev)) if *prev == x => {
+                *n += 1;
+                Try::from_ok(acc)
+            }
+            _ => match last.replace((1, x)) {
+                None => Try::from_ok(acc),
+                Some(prev) => f(acc, prev),
+            },
+        })?;
+
+        match self.last.take() {
+            None => Try::from_ok(acc),
+            Some(x) => f(acc, x),
+        }
+    }
+}
+
+impl<I> FusedTryIterator for DedupWithCount<I>
+where
+    I: FusedTryIterator,
+    I::Item: PartialEq,
+{
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+pub type Duplicates<I, M> = DuplicatesBy<
+    I,
+    FnWrapper<fn(&<I as TryIterator>::Item) -> <I as TryIterator>::Item, <I as TryIterator>::Error>,
+    M,
+>;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DuplicatesBy<I, F, M> {
+    iter: I,
+    f: F,
+    seen: M,
+    // the number of keys that were seen once and are still waiting for their
+    // duplicate
+    pending: usize,
+}
+
+impl<I, F, M> DuplicatesBy<I, F, M>
+where M: Default
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f, seen: M::default(), pending: 0 }
+    }
+}
+
+impl<I, F, M, R> TryIterator for DuplicatesBy<I, F, M>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+    M: GroupMap<R::Ok, bool>,
+{
+    type Item = I::Item;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // a pending key is yielded on its next occurrence, any other key
+        // takes at least two items
+        let (_, upper) = self.iter.size_hint();
+        (
+            0,
+            upper.map(|x| {
+                let pending = cmp::min(x, self.pending);
+                pending + (x - pending) / 2
+            }),
+        )
+    }
+
+    // every key maps to whether a duplicate has been yielded for it, so that
+    // each duplicate is only yielded on its second occurrence
+    fn try_fold<Acc, G, Q>(&mut self, acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        let f = &mut self.f;
+        let seen = &mut self.seen;
+        let pending = &mut self.pending;
+        self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            let key = f(&x)?;
+            match seen.get_mut(&key) {
+                None => {
+                    seen.insert(key, false);
+                    *pending += 1;
+                    Try::from_ok(acc)
+                }
+                Some(yielded) if !*yielded => {
+                    *yielded = true;
+                    *pending -= 1;
+                    g(acc, x)
+                }
+                Some(_) => Try::from_ok(acc),
+            }
+        })
+    }
+}
+
+impl<I, F, M, R> FusedTryIterator for DuplicatesBy<I, F, M>
+where
+    I: FusedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+    M: GroupMap<R::Ok, bool>,
+{
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExpectLen<I> {
+    iter: I,
+    n: usize,
+    count: usize,
+    done: bool,
+}
+
+impl<I> ExpectLen<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n, count: 0, done: false }
+    }
+}
+
+impl<I> TryIterator for ExpectLen<I>
+where I: TryIterator
+{
+    type Item = I::Item;
+    type Error = LengthError<I::Error>;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    // never yields more than `n` items, the underlying iterator says how
+    // many of them are actually there
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            size_hint::ZERO
+        } else {
+            size_hint::min(self.iter.size_hint(), self.n - self.count)
+        }
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        if self.done {
+            return Try::from_ok(acc);
+        }
+
+        let n = self.n;
+        let count = &mut self.count;
+        let done = &mut self.done;
+        let acc = self.iter.map_err_mut(LengthError::IterError).try_fold(acc, |acc, x| {
+            if *count == n {
+                *done = true;
+                let mismatch = LengthMismatch { expected: n, actual: n + 1 };
+                return Try::from_error(LengthError::Mismatch(mismatch).into());
+            }
+            *count += 1;
+            f(acc, x)
+        })?;
+
+        self.done = true;
+        if self.count < n {
+            let mismatch = LengthMismatch { expected: n, actual: self.count };
+            Err(LengthError::Mismatch(mismatch))?;
+        }
+        Try::from_ok(acc)
+    }
+}
+
+impl<I> ExactSizeTryIterator for ExpectLen<I> where I: ExactSizeTryIterator {}
+
+impl<I> FusedTryIterator for ExpectLen<I> where I: TryIterator {}
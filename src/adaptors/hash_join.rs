@@ -0,0 +1,141 @@
+use super::*;
+
+use alloc::vec::{self, Vec};
+
+// the build side is kept in the order it was read, and the map only points
+// each key at its bucket of row indices. that way unmatched rows come out in
+// the order they were inserted, whichever map is used
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct HashJoin<A, B, FA, FB, M>
+where
+    A: TryIterator,
+    B: TryIterator,
+{
+    a: Fuse<A>,
+    b: B,
+    built: bool,
+    map: M,
+    // each row along with the index of its bucket
+    rows: Vec<(B::Item, usize)>,
+    // the row indices of each key, and whether the key was probed
+    buckets: Vec<(Vec<usize>, bool)>,
+    // the left item, its bucket and the position of the next match in it
+    matches: Option<(A::Item, usize, usize)>,
+    unmatched: Option<vec::IntoIter<(B::Item, usize)>>,
+    left_key: FA,
+    right_key: FB,
+}
+
+impl<A, B, FA, FB, M> HashJoin<A, B, FA, FB, M>
+where
+    A: TryIterator,
+    B: TryIterator,
+    M: Default,
+{
+    pub(crate) fn new(a: A, b: B, left_key: FA, right_key: FB) -> Self {
+        Self {
+            a: Fuse::new(a),
+            b,
+            built: false,
+            map: M::default(),
+            rows: Vec::new(),
+            buckets: Vec::new(),
+            matches: None,
+            unmatched: None,
+            left_key,
+            right_key,
+        }
+    }
+}
+
+impl<A, B, K, FA, FB, M> TryIterator for HashJoin<A, B, FA, FB, M>
+where
+    A: TryIterator,
+    B: TryIterator,
+    A::Item: Clone,
+    B::Item: Clone,
+    A::Error: From<B::Error>,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+    M: GroupMap<K, usize>,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type Error = A::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some((x, bucket, pos)) = self.matches.take() {
+            let indices = &self.buckets[bucket].0;
+            let y = self.rows[indices[pos]].0.clone();
+            if pos + 1 == indices.len() {
+                return Ok(Some(EitherOrBoth::Both(x, y)));
+            }
+            let item = EitherOrBoth::Both(x.clone(), y);
+            self.matches = Some((x, bucket, pos + 1));
+            return Ok(Some(item));
+        }
+
+        // the build side is drained into the map before anything is probed.
+        // an error leaves the partially built map in place, so calling `next`
+        // again resumes building where it left off
+        if !self.built {
+            let map = &mut self.map;
+            let rows = &mut self.rows;
+            let buckets = &mut self.buckets;
+            let right_key = &mut self.right_key;
+            self.b.try_for_each(|y| {
+                let key = right_key(&y);
+                let bucket = match map.get_mut(&key) {
+                    Some(&mut bucket) => bucket,
+                    None => {
+                        map.insert(key, buckets.len());
+                        buckets.push((Vec::new(), false));
+                        buckets.len() - 1
+                    }
+                };
+                buckets[bucket].0.push(rows.len());
+                rows.push((y, bucket));
+                Ok::<(), A::Error>(())
+            })?;
+            self.built = true;
+        }
+
+        if let Some(x) = self.a.next()? {
+            return match self.map.get_mut(&(self.left_key)(&x)) {
+                None => Ok(Some(EitherOrBoth::Left(x))),
+                Some(&mut bucket) => {
+                    self.buckets[bucket].1 = true;
+                    self.matches = Some((x, bucket, 0));
+                    self.next()
+                }
+            };
+        }
+
+        let rows = &mut self.rows;
+        let buckets = &self.buckets;
+        let unmatched = self.unmatched.get_or_insert_with(|| mem::take(rows).into_iter());
+        Ok(unmatched.find(|&(_, bucket)| !buckets[bucket].1).map(|(y, _)| EitherOrBoth::Right(y)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = match self.matches {
+            None => 0,
+            Some((_, bucket, pos)) => self.buckets[bucket].0.len() - pos,
+        };
+        let (lower, _) = self.a.size_hint();
+        (lower.saturating_add(pending), None)
+    }
+}
+
+impl<A, B, K, FA, FB, M> FusedTryIterator for HashJoin<A, B, FA, FB, M>
+where
+    A: TryIterator,
+    B: TryIterator,
+    A::Item: Clone,
+    B::Item: Clone,
+    A::Error: From<B::Error>,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+    M: GroupMap<K, usize>,
+{
+}
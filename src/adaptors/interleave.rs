@@ -0,0 +1,84 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    flag: bool,
+}
+
+impl<A, B> Interleave<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a: Fuse::new(a), b: Fuse::new(b), flag: false }
+    }
+}
+
+impl<A, B> TryIterator for Interleave<A, B>
+where
+    A: TryIterator,
+    B: TryIterator<Item = A::Item>,
+    A::Error: From<B::Error>,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    // the turn only passes to the other side once an item has been produced,
+    // so after an error the next call polls the side that failed again
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let x = if self.flag { self.b.next()? } else { self.a.next()? };
+        match x {
+            Some(x) => {
+                self.flag = !self.flag;
+                Ok(Some(x))
+            }
+            None if self.flag => self.a.next(),
+            None => Ok(self.b.next()?),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add(self.a.size_hint(), self.b.size_hint())
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, mut acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        if self.flag {
+            match self.b.map_err_mut(A::Error::from).next()? {
+                None => return self.a.try_fold(acc, f),
+                Some(y) => {
+                    self.flag = false;
+                    acc = f(acc, y)?;
+                }
+            }
+        }
+
+        let flag = &mut self.flag;
+        let b = &mut self.b;
+        acc = self.a.try_fold(acc, |acc, x| {
+            *flag = true;
+            let acc = f(acc, x)?;
+            match b.map_err_mut(A::Error::from).next()? {
+                None => Try::from_ok(acc),
+                Some(y) => {
+                    *flag = false;
+                    f(acc, y)
+                }
+            }
+        })?;
+
+        self.b.map_err_mut(A::Error::from).try_fold(acc, f)
+    }
+}
+
+impl<A, B> FusedTryIterator for Interleave<A, B>
+where
+    A: TryIterator,
+    B: TryIterator<Item = A::Item>,
+    A::Error: From<B::Error>,
+{
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InterleaveShortest<A, B> {
+    a: A,
+    b: B,
+    flag: bool,
+}
+
+impl<A, B> InterleaveShortest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b, flag: false }
+    }
+}
+
+impl<A, B> TryIterator for InterleaveShortest<A, B>
+where
+    A: TryIterator,
+    B: TryIterator<Item = A::Item>,
+    A::Error: From<B::Error>,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    // as with `Interleave`, an error does not pass the turn to the other side
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let x = if self.flag { self.b.next()? } else { self.a.next()? };
+        if x.is_some() {
+            self.flag = !self.flag;
+        }
+        Ok(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (current, other) = if self.flag {
+            (self.b.size_hint(), self.a.size_hint())
+        } else {
+            (self.a.size_hint(), self.b.size_hint())
+        };
+        size_hint::min(size_hint::mul(current, 2), size_hint::add(size_hint::mul(other, 2), 1))
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, mut acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        if self.flag {
+            match self.b.map_err_mut(A::Error::from).next()? {
+                None => return Try::from_ok(acc),
+                Some(y) => {
+                    self.flag = false;
+                    acc = f(acc, y)?;
+                }
+            }
+        }
+
+        let flag = &mut self.flag;
+        let b = &mut self.b;
+        self.a
+            .try_fold(acc, |acc, x| {
+                *flag = true;
+                let acc = LoopState::continue_with_try(f(acc, x))?;
+                match b.map_err_mut(A::Error::from).next()? {
+                    None => LoopState::Break(acc),
+                    Some(y) => {
+                        *flag = false;
+                        LoopState::continue_with_try(f(acc, y))
+                    }
+                }
+            })
+            .into_try()
+    }
+}
+
+impl<A, B> FusedTryIterator for InterleaveShortest<A, B>
+where
+    A: FusedTryIterator,
+    B: FusedTryIterator<Item = A::Item>,
+    A::Error: From<B::Error>,
+{
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I>
+where I: TryIterator
+{
+    inner: IntersperseWith<I, FnWrapper<Separator<I::Item>, I::Error>>,
+}
+
+impl<I> Intersperse<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, sep: I::Item) -> Self {
+        Self { inner: IntersperseWith::new(iter, FnWrapper::new(Separator(sep))) }
+    }
+}
+
+impl<I> TryIterator for Intersperse<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        self.inner.try_fold(acc, f)
+    }
+}
+
+impl<I> FusedTryIterator for Intersperse<I>
+where
+    I: FusedTryIterator,
+    I::Item: Clone,
+{
+}
+
+#[derive(Clone, Debug)]
+struct Separator<T>(T);
+
+impl<T> FnOnce<()> for Separator<T> {
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, (): ()) -> Self::Output {
+        self.0
+    }
+}
+
+impl<T> FnMut<()> for Separator<T>
+where T: Clone
+{
+    extern "rust-call" fn call_mut(&mut self, (): ()) -> Self::Output {
+        self.0.clone()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I, F>
+where I: TryIterator
+{
+    iter: I,
+    f: F,
+    peeked: Option<I::Item>,
+    needs_sep: bool,
+}
+
+impl<I, F> IntersperseWith<I, F>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f, peeked: None, needs_sep: false }
+    }
+}
+
+impl<I, F, R> TryIterator for IntersperseWith<I, F>
+where
+    I: TryIterator,
+    F: FnMut() -> R,
+    R: Try<Ok = I::Item>,
+    R::Error: From<I::Error>,
+{
+    type Item = I::Item;
+    type Error = R::Error;
+
+    // an item is read ahead to decide whether a separator is due. if creating
+    // the separator fails, that item stays buffered and the separator is
+    // attempted again on the next call
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.peeked.is_none() {
+            self.peeked = self.iter.next()?;
+        }
+        if self.needs_sep && self.peeked.is_some() {
+            let sep = (self.f)()?;
+            self.needs_sep = false;
+            Ok(Some(sep))
+        } else {
+            let x = self.peeked.take();
+            self.needs_sep = x.is_some();
+            Ok(x)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let items = size_hint::add(self.iter.size_hint(), self.peeked.is_some() as usize);
+        let len = size_hint::mul(items, 2);
+        if self.needs_sep || items.0 == 0 { len } else { size_hint::sub(len, 1) }
+    }
+
+    fn try_fold<Acc, G, Q>(&mut self, mut acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        // go through `next` until the last thing produced was an item, at
+        // which point every remaining item is preceded by a separator
+        while self.peeked.is_some() || !self.needs_sep {
+            match self.next()? {
+                None => return Try::from_ok(acc),
+                Some(x) => acc = g(acc, x)?,
+            }
+        }
+
+        let f = &mut self.f;
+        let peeked = &mut self.peeked;
+        let needs_sep = &mut self.needs_sep;
+        self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            *peeked = Some(x);
+            let sep = f()?;
+            *needs_sep = false;
+            let acc = g(acc, sep)?;
+            *needs_sep = true;
+            match peeked.take() {
+                None => unreachable!(),
+                Some(x) => g(acc, x),
+            }
+        })
+    }
+}
+
+impl<I, F, R> FusedTryIterator for IntersperseWith<I, F>
+where
+    I: FusedTryIterator,
+    F: FnMut() -> R,
+    R: Try<Ok = I::Item>,
+    R::Error: From<I::Error>,
+{
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+use alloc::vec::Vec;
+
+// the items read from an iterator so far. items are only read on demand, and
+// an error from the underlying iterator leaves the buffer unchanged
+#[derive(Clone, Debug)]
+pub(crate) struct LazyBuffer<I, T> {
+    iter: I,
+    done: bool,
+    buffer: Vec<T>,
+}
+
+impl<I> LazyBuffer<I, I::Item>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, done: false, buffer: Vec::new() }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    // the total number of items, including the ones that haven't been read
+    pub(crate) fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.done {
+            true => (self.len(), Some(self.len())),
+            false => size_hint::add(self.iter.size_hint(), self.len()),
+        }
+    }
+
+    pub(crate) fn get_next(&mut self) -> Result<bool, I::Error> {
+        if self.done {
+            return Ok(false);
+        }
+        match self.iter.next()? {
+            None => {
+                self.done = true;
+                Ok(false)
+            }
+            Some(x) => {
+                self.buffer.push(x);
+                Ok(true)
+            }
+        }
+    }
+
+    pub(crate) fn prefill(&mut self, len: usize) -> Result<(), I::Error> {
+        while self.len() < len && self.get_next()? {}
+        Ok(())
+    }
+
+    pub(crate) fn get_at(&self, indices: &[usize]) -> Vec<I::Item>
+    where I::Item: Clone {
+        indices.iter().map(|&i| self.buffer[i].clone()).collect()
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeJoinBy<A, B, F>
+where
+    A: TryIterator,
+    B: TryIterator,
+{
+    a: Fuse<A>,
+    b: Fuse<B>,
+    left: Option<A::Item>,
+    right: Option<B::Item>,
+    f: F,
+}
+
+impl<A, B, F> MergeJoinBy<A, B, F>
+where
+    A: TryIterator,
+    B: TryIterator,
+{
+    pub(crate) fn new(a: A, b: B, f: F) -> Self {
+        Self { a: Fuse::new(a), b: Fuse::new(b), left: None, right: None, f }
+    }
+}
+
+impl<A, B, F, R> TryIterator for MergeJoinBy<A, B, F>
+where
+    A: TryIterator,
+    B: TryIterator,
+    F: FnMut(&A::Item, &B::Item) -> R,
+    R: Try<Ok = Ordering>,
+    R::Error: From<A::Error> + From<B::Error>,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        // buffer both heads before comparing, so that an error on one side
+        // never discards an item that was already read from the other side
+        if self.left.is_none() {
+            self.left = self.a.next()?;
+        }
+        if self.right.is_none() {
+            self.right = self.b.next()?;
+        }
+
+        Ok(match (self.left.take(), self.right.take()) {
+            (None, None) => None,
+            (Some(x), None) => Some(EitherOrBoth::Left(x)),
+            (None, Some(y)) => Some(EitherOrBoth::Right(y)),
+            (Some(x), Some(y)) => match (self.f)(&x, &y).into_result() {
+                Ok(Ordering::Less) => {
+                    self.right = Some(y);
+                    Some(EitherOrBoth::Left(x))
+                }
+                Ok(Ordering::Greater) => {
+                    self.left = Some(x);
+                    Some(EitherOrBoth::Right(y))
+                }
+                Ok(Ordering::Equal) => Some(EitherOrBoth::Both(x, y)),
+                Err(e) => {
+                    self.left = Some(x);
+                    self.right = Some(y);
+                    return Err(e);
+                }
+            },
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = size_hint::add(self.a.size_hint(), self.left.is_some() as usize);
+        let (b_lower, b_upper) = size_hint::add(self.b.size_hint(), self.right.is_some() as usize);
+        (cmp::max(a_lower, b_lower), try { a_upper?.checked_add(b_upper?)? })
+    }
+}
+
+impl<A, B, F, R> FusedTryIterator for MergeJoinBy<A, B, F>
+where
+    A: TryIterator,
+    B: TryIterator,
+    F: FnMut(&A::Item, &B::Item) -> R,
+    R: Try<Ok = Ordering>,
+    R::Error: From<A::Error> + From<B::Error>,
+{
+}
@@ -102,15 +102,20 @@ where I: DoubleEndedTryIterator
     }
 
     fn try_nth_back(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
-        Ok(match self.peeked.take() {
-            Some(None) => Err(n),
-            Some(Some(x)) => match self.iter.try_nth(n)? {
-                Ok(x) => Ok(x),
-                Err(0) => Ok(x),
-                Err(n) => Err(n),
+        match self.peeked.take() {
+            Some(None) => Ok(Err(n)),
+            // the peeked item is the front, so it stays peeked unless the
+            // underlying iterator runs out before reaching it
+            Some(Some(x)) => match self.iter.try_nth_back(n) {
+                Ok(Err(0)) => Ok(Ok(x)),
+                Ok(Err(n)) => Ok(Err(n - 1)),
+                result => {
+                    self.peeked = Some(Some(x));
+                    result
+                }
             },
-            None => self.iter.try_nth(n)?,
-        })
+            None => self.iter.try_nth_back(n),
+        }
     }
 
     fn try_rfold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
@@ -120,7 +125,7 @@ where I: DoubleEndedTryIterator
         R::Error: From<Self::Error>,
     {
         match self.peeked.take() {
-            Some(None) => return Try::from_ok(acc),
+            Some(None) => Try::from_ok(acc),
             Some(Some(x)) => match self.iter.try_rfold(acc, &mut f).into_result() {
                 Ok(acc) => f(acc, x),
                 Err(e) => {
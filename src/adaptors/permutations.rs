@@ -0,0 +1,141 @@
+use super::*;
+
+use super::lazy_buffer::LazyBuffer;
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Permutations<I>
+where I: TryIterator
+{
+    vals: LazyBuffer<I, I::Item>,
+    state: PermutationState,
+}
+
+#[derive(Clone, Debug)]
+enum PermutationState {
+    // nothing has been yielded yet
+    Start { k: usize },
+    // the first `min_n` items have been read, and the source may have more
+    Buffered { k: usize, min_n: usize },
+    // all items have been read
+    Loaded { indices: Vec<usize>, cycles: Vec<usize> },
+    End,
+}
+
+impl<I> Permutations<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I, k: usize) -> Self {
+        Self { vals: LazyBuffer::new(iter), state: PermutationState::Start { k } }
+    }
+}
+
+impl<I> TryIterator for Permutations<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    // while the source still has items, every new item gives rise to exactly
+    // one new permutation, so items are read one at a time. once the source
+    // runs out, the remaining permutations are generated from the buffer.
+    // the state only changes after reading succeeded
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let vals = &mut self.vals;
+        match self.state {
+            PermutationState::Start { k: 0 } => {
+                self.state = PermutationState::End;
+                Ok(Some(Vec::new()))
+            }
+            PermutationState::Start { k } => {
+                vals.prefill(k)?;
+                if vals.len() < k {
+                    self.state = PermutationState::End;
+                    return Ok(None);
+                }
+                self.state = PermutationState::Buffered { k, min_n: k };
+                Ok(Some(vals.get_at(&(0..k).collect::<Vec<_>>())))
+            }
+            PermutationState::Buffered { k, min_n } => {
+                if vals.get_next()? {
+                    let indices: Vec<_> = (0..k - 1).chain(Some(min_n)).collect();
+                    self.state = PermutationState::Buffered { k, min_n: min_n + 1 };
+                    return Ok(Some(vals.get_at(&indices)));
+                }
+                let n = min_n;
+                let mut indices: Vec<_> = (0..n).collect();
+                let mut cycles: Vec<_> = (n - k..n).rev().collect();
+                // skip the permutations that were yielded while buffering
+                for _ in 0..n - k + 1 {
+                    if advance(&mut indices, &mut cycles) {
+                        self.state = PermutationState::End;
+                        return Ok(None);
+                    }
+                }
+                let item = vals.get_at(&indices[..k]);
+                self.state = PermutationState::Loaded { indices, cycles };
+                Ok(Some(item))
+            }
+            PermutationState::Loaded { ref mut indices, ref mut cycles } => {
+                if advance(indices, cycles) {
+                    self.state = PermutationState::End;
+                    return Ok(None);
+                }
+                Ok(Some(vals.get_at(&indices[..cycles.len()])))
+            }
+            PermutationState::End => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = |n: usize| -> Option<usize> {
+            let total = |k: usize| match n < k {
+                true => Some(0),
+                false => (n - k + 1..=n).try_fold(1usize, |acc, i| acc.checked_mul(i)),
+            };
+            match self.state {
+                PermutationState::Start { k } => total(k),
+                PermutationState::Buffered { k, min_n } => {
+                    Some(total(k)?.saturating_sub(min_n - k + 1))
+                }
+                PermutationState::Loaded { ref indices, ref cycles } => {
+                    cycles.iter().enumerate().try_fold(0usize, |acc, (i, &c)| {
+                        acc.checked_mul(indices.len() - i)?.checked_add(c)
+                    })
+                }
+                PermutationState::End => Some(0),
+            }
+        };
+        let (lower, upper) = self.vals.size_hint();
+        (size_hint::lower_with_errors(self.vals.len(), lower, remaining), upper.and_then(remaining))
+    }
+}
+
+impl<I> FusedTryIterator for Permutations<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+}
+
+// moves `indices` to the next permutation, returns `true` once all
+// permutations have been visited
+fn advance(indices: &mut [usize], cycles: &mut [usize]) -> bool {
+    let n = indices.len();
+    let k = cycles.len();
+    for i in (0..k).rev() {
+        if cycles[i] == 0 {
+            cycles[i] = n - i - 1;
+            indices[i..].rotate_left(1);
+        } else {
+            let swap_index = n - cycles[i];
+            indices.swap(i, swap_index);
+            cycles[i] -= 1;
+            return false;
+        }
+    }
+    true
+}
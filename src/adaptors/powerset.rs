@@ -0,0 +1,78 @@
+use super::*;
+
+use alloc::vec::Vec;
+
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Powerset<I>
+where I: TryIterator
+{
+    combs: Combinations<I>,
+}
+
+impl<I> Powerset<I>
+where I: TryIterator
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self { combs: Combinations::new(iter, 0) }
+    }
+}
+
+impl<I> Clone for Powerset<I>
+where
+    I: TryIterator,
+    Combinations<I>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { combs: self.combs.clone() }
+    }
+}
+
+impl<I> Debug for Powerset<I>
+where
+    I: TryIterator,
+    Combinations<I>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Powerset").field("combs", &self.combs).finish()
+    }
+}
+
+impl<I> TryIterator for Powerset<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+    type Error = I::Error;
+
+    // subsets are yielded in order of increasing size, reusing the items that
+    // were read for the smaller subsets
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some(x) = self.combs.next()? {
+            return Ok(Some(x));
+        }
+        let k = self.combs.k();
+        if k == 0 || k < self.combs.n() {
+            self.combs.reset(k + 1);
+            return self.combs.next();
+        }
+        Ok(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = |n: usize| -> Option<usize> {
+            (self.combs.k() + 1..=n).try_fold(self.combs.remaining_for(n)?, |acc, k| {
+                acc.checked_add(size_hint::checked_binomial(n, k)?)
+            })
+        };
+        let (lower, upper) = self.combs.pool_size_hint();
+        (size_hint::lower_with_errors(self.combs.n(), lower, remaining), upper.and_then(remaining))
+    }
+}
+
+impl<I> FusedTryIterator for Powerset<I>
+where
+    I: TryIterator,
+    I::Item: Clone,
+{
+}
@@ -28,14 +28,7 @@ where I: TryIterator
     }
 
     fn try_nth(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
-        if self.n == 0 {
-            self.iter.try_nth(n)
-        } else {
-            match self.iter.try_nth(mem::take(&mut self.n) - 1)? {
-                Ok(_) => self.iter.try_nth(n),
-                Err(_) => Ok(Err(n)),
-            }
-        }
+        if self.skip()? { self.iter.try_nth(n) } else { Ok(Err(n)) }
     }
 
     fn try_fold<Acc, F, R>(&mut self, acc: Acc, f: F) -> R
@@ -44,13 +37,30 @@ where I: TryIterator
         R: Try<Ok = Acc>,
         R::Error: From<Self::Error>,
     {
-        let n = self.n;
-        self.n = 0;
-        if n > 0 && self.iter.nth(n - 1)?.is_none() {
-            Try::from_ok(acc)
-        } else {
-            self.iter.try_fold(acc, f)
+        if self.skip()? { self.iter.try_fold(acc, f) } else { Try::from_ok(acc) }
+    }
+}
+
+impl<I> Skip<I>
+where I: TryIterator
+{
+    // skips the items that haven't been skipped yet, one at a time so that an
+    // error doesn't lose track of how many are left. returns `false` if the
+    // underlying iterator ran out
+    fn skip(&mut self) -> Result<bool, I::Error> {
+        if self.n == 0 {
+            return Ok(true);
+        }
+        let n = &mut self.n;
+        let state: LoopState<_, _, _, I::Error> = self.iter.try_fold((), |(), _| {
+            *n -= 1;
+            if *n == 0 { LoopState::Break(true) } else { LoopState::Continue(()) }
+        });
+        let found = state.map_continue(|()| false).into_try::<Result<_, I::Error>>()?;
+        if !found {
+            self.n = 0;
         }
+        Ok(found)
     }
 }
 
@@ -0,0 +1,207 @@
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+pub type Split<I, F> = SplitFold<
+    I,
+    F,
+    fn() -> Vec<<I as TryIterator>::Item>,
+    fn(Vec<<I as TryIterator>::Item>, <I as TryIterator>::Item) -> Vec<<I as TryIterator>::Item>,
+>;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitFold<I, F, N, G>
+where N: FnOnce<()>
+{
+    iter: I,
+    f: F,
+    init: N,
+    g: G,
+    current: Option<N::Output>,
+    // the number of splits left, if there's a limit
+    splits: Option<usize>,
+    inclusive: bool,
+    done: bool,
+}
+
+impl<I, F, N, G> SplitFold<I, F, N, G>
+where N: FnOnce<()>
+{
+    pub(crate) fn new(iter: I, f: F, init: N, g: G) -> Self {
+        Self { iter, f, init, g, current: None, splits: None, inclusive: false, done: false }
+    }
+
+    pub(crate) fn inclusive(self) -> Self {
+        Self { inclusive: true, ..self }
+    }
+
+    pub(crate) fn splitn(self, n: usize) -> Self {
+        Self { splits: Some(n.saturating_sub(1)), done: n == 0, ..self }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> Split<I, F>
+where I: TryIterator
+{
+    pub(crate) fn split(iter: I, f: F) -> Self {
+        Self::new(iter, f, Vec::new, push)
+    }
+}
+
+impl<I, F, N, G, R, B> TryIterator for SplitFold<I, F, N, G>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = bool>,
+    R::Error: From<I::Error>,
+    N: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+    type Item = B;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return size_hint::ZERO;
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let (lower, upper) = match self.inclusive {
+            // every segment contains at least its separator
+            true => {
+                let pending = self.current.is_some() as usize;
+                (
+                    cmp::min(lower.saturating_add(pending), 1),
+                    upper.and_then(|x| x.checked_add(pending)),
+                )
+            }
+            false => (1, upper.and_then(|x| x.checked_add(1))),
+        };
+        match self.splits {
+            None => (lower, upper),
+            Some(splits) => size_hint::min((lower, upper), splits + 1),
+        }
+    }
+
+    // a segment is only yielded once it is complete. if the predicate or the
+    // underlying iterator fails, the items of the current segment are kept
+    fn try_fold<Acc, H, Q>(&mut self, acc: Acc, mut h: H) -> Q
+    where
+        H: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        if self.done {
+            return Try::from_ok(acc);
+        }
+
+        let f = &mut self.f;
+        let init = &mut self.init;
+        let g = &mut self.g;
+        let current = &mut self.current;
+        let splits = &mut self.splits;
+        let inclusive = self.inclusive;
+        let acc = self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| {
+            if *splits == Some(0) || !f(&x)? {
+                let segment = current.take().unwrap_or_else(&mut *init);
+                *current = Some(g(segment, x));
+                return Try::from_ok(acc);
+            }
+            if let Some(splits) = splits {
+                *splits -= 1;
+            }
+            let segment = current.take().unwrap_or_else(&mut *init);
+            match inclusive {
+                true => h(acc, g(segment, x)),
+                false => h(acc, segment),
+            }
+        })?;
+
+        self.done = true;
+        match self.current.take() {
+            Some(segment) => h(acc, segment),
+            None if self.inclusive => Try::from_ok(acc),
+            None => h(acc, (self.init)()),
+        }
+    }
+}
+
+impl<I, F, N, G, R, B> FusedTryIterator for SplitFold<I, F, N, G>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = bool>,
+    R::Error: From<I::Error>,
+    N: FnMut() -> B,
+    G: FnMut(B, I::Item) -> B,
+{
+}
+
+// splits from the back, the items of each segment are still yielded in their
+// original order
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RSplit<I, F>
+where I: DoubleEndedTryIterator
+{
+    inner: SplitFold<Rev<I>, F, fn() -> Vec<I::Item>, fn(Vec<I::Item>, I::Item) -> Vec<I::Item>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F> RSplit<I, F>
+where I: DoubleEndedTryIterator
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { inner: Split::split(Rev::new(iter), f) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F, R> TryIterator for RSplit<I, F>
+where
+    I: DoubleEndedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = bool>,
+    R::Error: From<I::Error>,
+{
+    type Item = Vec<I::Item>;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn try_fold<Acc, H, Q>(&mut self, acc: Acc, mut h: H) -> Q
+    where
+        H: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        self.inner.try_fold(acc, |acc, mut segment| {
+            segment.reverse();
+            h(acc, segment)
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I, F, R> FusedTryIterator for RSplit<I, F>
+where
+    I: DoubleEndedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try<Ok = bool>,
+    R::Error: From<I::Error>,
+{
+}
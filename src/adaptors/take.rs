@@ -28,20 +28,13 @@ where I: TryIterator
     }
 
     fn try_nth(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
-        if self.n > n {
-            self.n -= n + 1;
-            self.iter.try_nth(n)
-        } else {
-            let k = mem::take(&mut self.n);
-            let n = match k {
-                0 => n,
-                k => match self.iter.try_nth(k - 1)? {
-                    Ok(_) => n - k,
-                    Err(m) => n - k + m + 1,
-                },
-            };
-            Ok(Err(n))
-        }
+        // goes through `try_fold` so that `self.n` stays correct when the
+        // underlying iterator fails halfway through
+        let state: LoopState<_, _, _, I::Error> = self.try_fold(n, |n, x| match n {
+            0 => LoopState::Break(Ok(x)),
+            n => LoopState::Continue(n - 1),
+        });
+        state.map_continue(Err).into_try()
     }
 
     fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
@@ -73,21 +66,11 @@ where I: DoubleEndedTryIterator + ExactSizeTryIterator
     }
 
     fn try_nth_back(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
-        let len = self.iter.len();
-        if self.n > n {
-            let m = len.saturating_sub(self.n) + n;
-            self.n -= n + 1;
-            self.iter.try_nth_back(m)
-        } else {
-            let n = match len {
-                0 => n,
-                len => match self.iter.try_nth_back(len - 1)? {
-                    Ok(_) => n - self.n,
-                    Err(k) => n - self.n + k + 1,
-                },
-            };
-            Ok(Err(n))
-        }
+        let state: LoopState<_, _, _, I::Error> = self.try_rfold(n, |n, x| match n {
+            0 => LoopState::Break(Ok(x)),
+            n => LoopState::Continue(n - 1),
+        });
+        state.map_continue(Err).into_try()
     }
 
     fn try_rfold<Acc, F, R>(&mut self, acc: Acc, f: F) -> R
@@ -0,0 +1,122 @@
+use super::*;
+
+pub trait HomogeneousTuple: Sized {
+    type Item;
+    type Buffer: Default + AsRef<[Option<Self::Item>]> + AsMut<[Option<Self::Item>]>;
+
+    fn from_buffer(buffer: &Self::Buffer) -> Option<Self>
+    where Self::Item: Clone;
+}
+
+macro_rules! replace_ty {
+    ($x:ident, $ty:ty) => {
+        $ty
+    };
+}
+
+macro_rules! homogeneous_tuple {
+    ($n:literal; $($x:ident)+) => {
+        impl<T> HomogeneousTuple for ($(replace_ty!($x, T),)+) {
+            type Item = T;
+            type Buffer = [Option<T>; $n];
+
+            fn from_buffer(buffer: &Self::Buffer) -> Option<Self>
+            where T: Clone {
+                match buffer {
+                    [$(Some($x)),+] => Some(($($x.clone(),)+)),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+homogeneous_tuple!(1; a);
+homogeneous_tuple!(2; a b);
+homogeneous_tuple!(3; a b c);
+homogeneous_tuple!(4; a b c d);
+homogeneous_tuple!(5; a b c d e);
+homogeneous_tuple!(6; a b c d e f);
+homogeneous_tuple!(7; a b c d e f g);
+homogeneous_tuple!(8; a b c d e f g h);
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TupleWindows<I, T>
+where T: HomogeneousTuple
+{
+    iter: I,
+    buffer: T::Buffer,
+    len: usize,
+}
+
+impl<I, T> TupleWindows<I, T>
+where T: HomogeneousTuple
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, buffer: T::Buffer::default(), len: 0 }
+    }
+}
+
+impl<I, T> TryIterator for TupleWindows<I, T>
+where
+    I: TryIterator,
+    I::Item: Clone,
+    T: HomogeneousTuple<Item = I::Item>,
+{
+    type Item = T;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.buffer.as_ref().len();
+        size_hint::sub(size_hint::add(self.iter.size_hint(), self.len), n - 1)
+    }
+
+    // same as `ArrayWindows`, in between windows the buffer holds the last
+    // `n - 1` items
+    fn try_fold<Acc, F, R>(&mut self, acc: Acc, mut f: F) -> R
+    where
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = Acc>,
+        R::Error: From<Self::Error>,
+    {
+        let buffer = &mut self.buffer;
+        let len = &mut self.len;
+        self.iter.try_fold(acc, |acc, x| {
+            let slots = buffer.as_mut();
+            slots[*len] = Some(x);
+            if *len + 1 < slots.len() {
+                *len += 1;
+                return Try::from_ok(acc);
+            }
+            let window = T::from_buffer(buffer);
+            let slots = buffer.as_mut();
+            slots.rotate_left(1);
+            slots[*len] = None;
+            match window {
+                None => unreachable!(),
+                Some(window) => f(acc, window),
+            }
+        })
+    }
+}
+
+impl<I, T> ExactSizeTryIterator for TupleWindows<I, T>
+where
+    I: ExactSizeTryIterator,
+    I::Item: Clone,
+    T: HomogeneousTuple<Item = I::Item>,
+{
+}
+
+impl<I, T> FusedTryIterator for TupleWindows<I, T>
+where
+    I: FusedTryIterator,
+    I::Item: Clone,
+    T: HomogeneousTuple<Item = I::Item>,
+{
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+pub type Unique<I, S> = UniqueBy<
+    I,
+    FnWrapper<fn(&<I as TryIterator>::Item) -> <I as TryIterator>::Item, <I as TryIterator>::Error>,
+    S,
+>;
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UniqueBy<I, F, S> {
+    iter: I,
+    f: F,
+    seen: S,
+}
+
+impl<I, F, S> UniqueBy<I, F, S>
+where S: Default
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f, seen: S::default() }
+    }
+}
+
+impl<I, F, S, R> TryIterator for UniqueBy<I, F, S>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+    S: KeySet<R::Ok>,
+{
+    type Item = I::Item;
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint().without_lower_bound()
+    }
+
+    fn try_fold<Acc, G, Q>(&mut self, acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        let f = &mut self.f;
+        let seen = &mut self.seen;
+        self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| match seen.insert(f(&x)?) {
+            true => g(acc, x),
+            false => Try::from_ok(acc),
+        })
+    }
+}
+
+impl<I, F, S, R> FusedTryIterator for UniqueBy<I, F, S>
+where
+    I: FusedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+    S: KeySet<R::Ok>,
+{
+}
@@ -0,0 +1,191 @@
+use super::*;
+
+use core::num::Wrapping;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ArithmeticError<E> {
+    Overflow,
+    IterError(E),
+}
+
+impl<E> ArithmeticError<E> {
+    pub fn is_overflow(&self) -> bool {
+        matches!(self, Self::Overflow)
+    }
+
+    pub fn iter_error(self) -> Option<E> {
+        match self {
+            Self::Overflow => None,
+            Self::IterError(e) => Some(e),
+        }
+    }
+}
+
+impl<E> From<E> for ArithmeticError<E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
+
+// like `Sum`, but reporting overflow as an error rather than panicking or
+// wrapping
+pub trait TrySum<A = Self>: Sized {
+    fn try_sum<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = A>;
+}
+
+pub trait TryProduct<A = Self>: Sized {
+    fn try_product<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = A>;
+}
+
+pub trait SaturatingSum<A = Self>: Sized {
+    fn saturating_sum<I>(iter: I) -> Result<Self, I::Error>
+    where I: TryIterator<Item = A>;
+}
+
+macro_rules! integer_impls {
+    ($($t:ty)*) => {$(
+        impl TrySum for $t {
+            fn try_sum<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = Self> {
+                iter.try_fold(0, |acc: $t, x| acc.checked_add(x).ok_or(ArithmeticError::Overflow))
+            }
+        }
+
+        impl<'a> TrySum<&'a $t> for $t {
+            fn try_sum<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = &'a $t> {
+                Self::try_sum(iter.copied())
+            }
+        }
+
+        impl TryProduct for $t {
+            fn try_product<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = Self> {
+                iter.try_fold(1, |acc: $t, x| acc.checked_mul(x).ok_or(ArithmeticError::Overflow))
+            }
+        }
+
+        impl<'a> TryProduct<&'a $t> for $t {
+            fn try_product<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = &'a $t> {
+                Self::try_product(iter.copied())
+            }
+        }
+
+        impl SaturatingSum for $t {
+            fn saturating_sum<I>(iter: I) -> Result<Self, I::Error>
+            where I: TryIterator<Item = Self> {
+                iter.fold(0, <$t>::saturating_add)
+            }
+        }
+
+        impl<'a> SaturatingSum<&'a $t> for $t {
+            fn saturating_sum<I>(iter: I) -> Result<Self, I::Error>
+            where I: TryIterator<Item = &'a $t> {
+                Self::saturating_sum(iter.copied())
+            }
+        }
+
+        // wrapping arithmetic can't overflow
+        impl TrySum for Wrapping<$t> {
+            fn try_sum<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = Self> {
+                Ok(iter.fold(Wrapping(0), |acc, x| acc + x)?)
+            }
+        }
+
+        impl<'a> TrySum<&'a Wrapping<$t>> for Wrapping<$t> {
+            fn try_sum<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = &'a Wrapping<$t>> {
+                Self::try_sum(iter.copied())
+            }
+        }
+
+        impl TryProduct for Wrapping<$t> {
+            fn try_product<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = Self> {
+                Ok(iter.fold(Wrapping(1), |acc, x| acc * x)?)
+            }
+        }
+
+        impl<'a> TryProduct<&'a Wrapping<$t>> for Wrapping<$t> {
+            fn try_product<I>(iter: I) -> Result<Self, ArithmeticError<I::Error>>
+            where I: TryIterator<Item = &'a Wrapping<$t>> {
+                Self::try_product(iter.copied())
+            }
+        }
+    )*};
+}
+
+integer_impls!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// the sum of `Option`s stops at the first `None`, just like `Sum` does
+impl<T, U> TrySum<Option<U>> for Option<T>
+where T: TrySum<U>
+{
+    fn try_sum<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = Option<U>> {
+        let mut found_none = false;
+        let sum = T::try_sum(iter.by_ref().map_while(|x| {
+            found_none = x.is_none();
+            x
+        }))?;
+        Ok(if found_none { None } else { Some(sum) })
+    }
+}
+
+impl<T, U> TryProduct<Option<U>> for Option<T>
+where T: TryProduct<U>
+{
+    fn try_product<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = Option<U>> {
+        let mut found_none = false;
+        let product = T::try_product(iter.by_ref().map_while(|x| {
+            found_none = x.is_none();
+            x
+        }))?;
+        Ok(if found_none { None } else { Some(product) })
+    }
+}
+
+impl<T, U, F> TrySum<Result<U, F>> for Result<T, F>
+where T: TrySum<U>
+{
+    fn try_sum<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = Result<U, F>> {
+        let mut error = None;
+        let sum = T::try_sum(iter.by_ref().map_while(|x| match x {
+            Ok(x) => Some(x),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }))?;
+        Ok(match error {
+            None => Ok(sum),
+            Some(e) => Err(e),
+        })
+    }
+}
+
+impl<T, U, F> TryProduct<Result<U, F>> for Result<T, F>
+where T: TryProduct<U>
+{
+    fn try_product<I>(mut iter: I) -> Result<Self, ArithmeticError<I::Error>>
+    where I: TryIterator<Item = Result<U, F>> {
+        let mut error = None;
+        let product = T::try_product(iter.by_ref().map_while(|x| match x {
+            Ok(x) => Some(x),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }))?;
+        Ok(match error {
+            None => Ok(product),
+            Some(e) => Err(e),
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+use core::{mem::MaybeUninit, ptr, slice};
+
+// a fixed capacity buffer whose first `len` elements are initialized. it is
+// used to build arrays one item at a time, which has to survive the
+// underlying iterator returning an error halfway through
+pub(crate) struct ArrayBuffer<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayBuffer<T, N> {
+    pub(crate) fn new() -> Self {
+        // an array of `MaybeUninit`s doesn't need to be initialized
+        let data = unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() };
+        Self { data, len: 0 }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    pub(crate) fn push(&mut self, x: T) {
+        assert!(!self.is_full());
+        self.data[self.len] = MaybeUninit::new(x);
+        self.len += 1;
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { ptr::read(self.data.as_ptr().add(self.len) as *const T) })
+    }
+
+    pub(crate) fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.data.as_mut_ptr() as *mut T;
+            let x = ptr::read(ptr);
+            ptr::copy(ptr.add(1), ptr, self.len - 1);
+            self.len -= 1;
+            Some(x)
+        }
+    }
+
+    pub(crate) fn take_array(&mut self) -> Option<[T; N]> {
+        if !self.is_full() {
+            return None;
+        }
+        self.len = 0;
+        Some(unsafe { ptr::read(self.data.as_ptr() as *const [T; N]) })
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayBuffer<T, N> {
+    fn drop(&mut self) {
+        let len = mem::replace(&mut self.len, 0);
+        unsafe {
+            let ptr = self.data.as_mut_ptr() as *mut T;
+            ptr::drop_in_place(slice::from_raw_parts_mut(ptr, len));
+        }
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayBuffer<T, N>
+where T: Clone
+{
+    fn clone(&self) -> Self {
+        let mut buffer = Self::new();
+        for x in self.as_slice() {
+            buffer.push(x.clone());
+        }
+        buffer
+    }
+}
+
+impl<T, const N: usize> Debug for ArrayBuffer<T, N>
+where T: Debug
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,77 @@
+// a collection with a fixed capacity that hands an item back when it is
+// full. implemented for the `arrayvec` and `heapless` types when those
+// features are enabled, and by any other collection that can run out of room
+pub trait BoundedCollection<T> {
+    fn try_push(&mut self, x: T) -> Result<(), T>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CapacityError<T, E> {
+    // the first item that didn't fit
+    Full(T),
+    IterError(E),
+}
+
+impl<T, E> CapacityError<T, E> {
+    pub fn full(self) -> Option<T> {
+        match self {
+            Self::Full(x) => Some(x),
+            Self::IterError(_) => None,
+        }
+    }
+
+    pub fn iter_error(self) -> Option<E> {
+        match self {
+            Self::Full(_) => None,
+            Self::IterError(e) => Some(e),
+        }
+    }
+}
+
+impl<T, E> From<E> for CapacityError<T, E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> BoundedCollection<T> for arrayvec::ArrayVec<T, CAP> {
+    fn try_push(&mut self, x: T) -> Result<(), T> {
+        arrayvec::ArrayVec::try_push(self, x).map_err(|e| e.element())
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> BoundedCollection<char> for arrayvec::ArrayString<CAP> {
+    fn try_push(&mut self, c: char) -> Result<(), char> {
+        arrayvec::ArrayString::try_push(self, c).map_err(|e| e.element())
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<'a, const CAP: usize> BoundedCollection<&'a str> for arrayvec::ArrayString<CAP> {
+    fn try_push(&mut self, s: &'a str) -> Result<(), &'a str> {
+        self.try_push_str(s).map_err(|e| e.element())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> BoundedCollection<T> for heapless::Vec<T, N> {
+    fn try_push(&mut self, x: T) -> Result<(), T> {
+        self.push(x)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> BoundedCollection<char> for heapless::String<N> {
+    fn try_push(&mut self, c: char) -> Result<(), char> {
+        self.push(c).map_err(|()| c)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, const N: usize> BoundedCollection<&'a str> for heapless::String<N> {
+    fn try_push(&mut self, s: &'a str) -> Result<(), &'a str> {
+        self.push_str(s).map_err(|()| s)
+    }
+}
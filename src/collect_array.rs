@@ -0,0 +1,48 @@
+use super::*;
+
+// the items that were read before the iterator ran out, when fewer than `N`
+// were available
+#[derive(Clone, Debug)]
+pub struct PartialArray<T, const N: usize> {
+    buffer: ArrayBuffer<T, N>,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    pub(crate) fn new(buffer: ArrayBuffer<T, N>) -> Self {
+        Self { buffer }
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.buffer.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.buffer.as_mut_slice()
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.buffer.pop()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum CollectArrayError<T, E, const N: usize> {
+    TooFew(PartialArray<T, N>),
+    // the full array along with the first item that didn't fit
+    TooMany([T; N], T),
+    IterError(E),
+}
+
+impl<T, E, const N: usize> From<E> for CollectArrayError<T, E, N> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
@@ -0,0 +1,34 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DuplicateKey<K, V> {
+    pub key: K,
+    pub first: V,
+    pub second: V,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CollectMapError<K, V, E> {
+    DuplicateKey(DuplicateKey<K, V>),
+    IterError(E),
+}
+
+impl<K, V, E> CollectMapError<K, V, E> {
+    pub fn duplicate_key(self) -> Option<DuplicateKey<K, V>> {
+        match self {
+            Self::DuplicateKey(d) => Some(d),
+            Self::IterError(_) => None,
+        }
+    }
+
+    pub fn iter_error(self) -> Option<E> {
+        match self {
+            Self::DuplicateKey(_) => None,
+            Self::IterError(e) => Some(e),
+        }
+    }
+}
+
+impl<K, V, E> From<E> for CollectMapError<K, V, E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
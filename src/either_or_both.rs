@@ -0,0 +1,111 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EitherOrBoth<L, R> {
+    Left(L),
+    Right(R),
+    Both(L, R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    pub fn has_left(&self) -> bool {
+        match self {
+            Self::Left(_) | Self::Both(_, _) => true,
+            Self::Right(_) => false,
+        }
+    }
+
+    pub fn has_right(&self) -> bool {
+        match self {
+            Self::Right(_) | Self::Both(_, _) => true,
+            Self::Left(_) => false,
+        }
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    pub fn is_both(&self) -> bool {
+        matches!(self, Self::Both(_, _))
+    }
+
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(x) | Self::Both(x, _) => Some(x),
+            Self::Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Right(y) | Self::Both(_, y) => Some(y),
+            Self::Left(_) => None,
+        }
+    }
+
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            Self::Both(x, y) => Some((x, y)),
+            _ => None,
+        }
+    }
+
+    pub fn just_left(self) -> Option<L> {
+        match self {
+            Self::Left(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn just_right(self) -> Option<R> {
+        match self {
+            Self::Right(y) => Some(y),
+            _ => None,
+        }
+    }
+
+    pub fn as_ref(&self) -> EitherOrBoth<&L, &R> {
+        match self {
+            Self::Left(x) => EitherOrBoth::Left(x),
+            Self::Right(y) => EitherOrBoth::Right(y),
+            Self::Both(x, y) => EitherOrBoth::Both(x, y),
+        }
+    }
+
+    pub fn flip(self) -> EitherOrBoth<R, L> {
+        match self {
+            Self::Left(x) => EitherOrBoth::Right(x),
+            Self::Right(y) => EitherOrBoth::Left(y),
+            Self::Both(x, y) => EitherOrBoth::Both(y, x),
+        }
+    }
+
+    pub fn map_left<F, T>(self, f: F) -> EitherOrBoth<T, R>
+    where F: FnOnce(L) -> T {
+        match self {
+            Self::Left(x) => EitherOrBoth::Left(f(x)),
+            Self::Right(y) => EitherOrBoth::Right(y),
+            Self::Both(x, y) => EitherOrBoth::Both(f(x), y),
+        }
+    }
+
+    pub fn map_right<F, T>(self, f: F) -> EitherOrBoth<L, T>
+    where F: FnOnce(R) -> T {
+        match self {
+            Self::Left(x) => EitherOrBoth::Left(x),
+            Self::Right(y) => EitherOrBoth::Right(f(y)),
+            Self::Both(x, y) => EitherOrBoth::Both(x, f(y)),
+        }
+    }
+
+    pub fn or(self, l: L, r: R) -> (L, R) {
+        match self {
+            Self::Left(x) => (x, r),
+            Self::Right(y) => (l, y),
+            Self::Both(x, y) => (x, y),
+        }
+    }
+}
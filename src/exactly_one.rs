@@ -0,0 +1,33 @@
+// the first two items of an iterator that was expected to have at most one,
+// along with the iterator itself, which hasn't been read any further
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TooMany<T, I> {
+    pub first: T,
+    pub second: T,
+    pub rest: I,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExactlyOneError<T, I, E> {
+    Empty,
+    TooMany(TooMany<T, I>),
+    IterError(E),
+}
+
+impl<T, I, E> From<E> for ExactlyOneError<T, I, E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AtMostOneError<T, I, E> {
+    TooMany(TooMany<T, I>),
+    IterError(E),
+}
+
+impl<T, I, E> From<E> for AtMostOneError<T, I, E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
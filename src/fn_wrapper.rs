@@ -6,7 +6,7 @@ pub struct FnWrapper<F, E> {
 }
 
 impl<F, E> FnWrapper<F, E> {
-    pub(crate) fn new(f: F) -> Self {
+    pub fn new(f: F) -> Self {
         Self { f, _marker: PhantomData }
     }
 }
@@ -0,0 +1,17 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FoldWhile<T> {
+    Continue(T),
+    Done(T),
+}
+
+impl<T> FoldWhile<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Continue(x) | Self::Done(x) => x,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self, Self::Done(_))
+    }
+}
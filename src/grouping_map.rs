@@ -0,0 +1,286 @@
+use super::*;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+use core::ops::{Add, Mul};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+// the operations on a map that the grouping consumers need. implemented for
+// `HashMap` and `BTreeMap`, and by any other map that should be collected into
+pub trait GroupMap<K, V>: Default {
+    fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+    fn remove(&mut self, key: &K) -> Option<V>;
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> GroupMap<K, V> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        HashMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> GroupMap<K, V> for BTreeMap<K, V>
+where K: Ord
+{
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        BTreeMap::get_mut(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+}
+
+pub type GroupingMapBy<I, F> = GroupingMap<KeyBy<I, F>>;
+
+#[derive(Clone, Debug)]
+#[must_use = "`GroupingMap` is lazy and does nothing unless consumed"]
+pub struct GroupingMap<I> {
+    iter: I,
+}
+
+impl<I> GroupingMap<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I, F> GroupingMapBy<I, F> {
+    pub(crate) fn by(iter: I, f: F) -> Self {
+        Self::new(KeyBy::new(iter, f))
+    }
+}
+
+impl<I, K, V> GroupingMap<I>
+where I: TryIterator<Item = (K, V)>
+{
+    pub fn fold<M, B, F>(self, init: B, f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, B>,
+        B: Clone,
+        F: FnMut(B, &K, V) -> B,
+    {
+        self.try_fold(init, FnWrapper::new(f))
+    }
+
+    pub fn try_fold<M, B, F, R>(mut self, init: B, mut f: F) -> Result<M, R::Error>
+    where
+        M: GroupMap<K, B>,
+        B: Clone,
+        F: FnMut(B, &K, V) -> R,
+        R: Try<Ok = B>,
+        R::Error: From<I::Error>,
+    {
+        let mut map = M::default();
+        self.iter.try_for_each(|(key, value)| {
+            match map.get_mut(&key) {
+                Some(acc) => {
+                    let old = mem::replace(acc, init.clone());
+                    *acc = f(old, &key, value)?;
+                }
+                None => {
+                    let acc = f(init.clone(), &key, value)?;
+                    map.insert(key, acc);
+                }
+            }
+            Ok::<_, R::Error>(())
+        })?;
+        Ok(map)
+    }
+
+    pub fn reduce<M, F>(self, f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(V, &K, V) -> V,
+    {
+        self.try_reduce(FnWrapper::new(f))
+    }
+
+    pub fn try_reduce<M, F, R>(mut self, mut f: F) -> Result<M, R::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(V, &K, V) -> R,
+        R: Try<Ok = V>,
+        R::Error: From<I::Error>,
+    {
+        // `f` takes the accumulator by value and `V` has nothing to leave in
+        // its place, so it's taken out of the map and put back
+        let mut map = M::default();
+        self.iter.try_for_each(|(key, value)| {
+            let value = match map.remove(&key) {
+                None => value,
+                Some(acc) => f(acc, &key, value)?,
+            };
+            map.insert(key, value);
+            Ok::<_, R::Error>(())
+        })?;
+        Ok(map)
+    }
+
+    pub fn sum<M>(self) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        V: Add<Output = V>,
+    {
+        self.reduce(|acc, _, x| acc + x)
+    }
+
+    pub fn product<M>(self) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        V: Mul<Output = V>,
+    {
+        self.reduce(|acc, _, x| acc * x)
+    }
+
+    pub fn min<M>(self) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        V: Ord,
+    {
+        self.min_by(|_, x, y| x.cmp(y))
+    }
+
+    pub fn min_by<M, F>(self, mut f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.merge(|acc, key, x| {
+            if f(key, acc, &x) == Ordering::Greater {
+                *acc = x;
+            }
+        })
+    }
+
+    pub fn min_by_key<M, F, T>(self, mut f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(&K, &V) -> T,
+        T: Ord,
+    {
+        self.min_by(|key, x, y| f(key, x).cmp(&f(key, y)))
+    }
+
+    pub fn max<M>(self) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        V: Ord,
+    {
+        self.max_by(|_, x, y| x.cmp(y))
+    }
+
+    pub fn max_by<M, F>(self, mut f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.merge(|acc, key, x| {
+            if f(key, acc, &x) != Ordering::Greater {
+                *acc = x;
+            }
+        })
+    }
+
+    pub fn max_by_key<M, F, T>(self, mut f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(&K, &V) -> T,
+        T: Ord,
+    {
+        self.max_by(|key, x, y| f(key, x).cmp(&f(key, y)))
+    }
+
+    // merges each value into the one that's already in the map for its key,
+    // without taking that one out of the map
+    fn merge<M, F>(mut self, mut f: F) -> Result<M, I::Error>
+    where
+        M: GroupMap<K, V>,
+        F: FnMut(&mut V, &K, V),
+    {
+        let mut map = M::default();
+        self.iter.try_for_each(|(key, value)| {
+            match map.get_mut(&key) {
+                Some(acc) => f(acc, &key, value),
+                None => {
+                    map.insert(key, value);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(map)
+    }
+}
+
+// pairs every item with its key, the key function is allowed to fail
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct KeyBy<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> KeyBy<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I, F, R> TryIterator for KeyBy<I, F>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+{
+    type Item = (R::Ok, I::Item);
+    type Error = R::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.find(|_| true)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn try_fold<Acc, G, Q>(&mut self, acc: Acc, mut g: G) -> Q
+    where
+        G: FnMut(Acc, Self::Item) -> Q,
+        Q: Try<Ok = Acc>,
+        Q::Error: From<Self::Error>,
+    {
+        let f = &mut self.f;
+        self.iter.map_err_mut(R::Error::from).try_fold(acc, |acc, x| g(acc, (f(&x)?, x)))
+    }
+}
+
+impl<I, F, R> FusedTryIterator for KeyBy<I, F>
+where
+    I: FusedTryIterator,
+    F: FnMut(&I::Item) -> R,
+    R: Try,
+    R::Error: From<I::Error>,
+{
+}
@@ -0,0 +1,91 @@
+// the pieces that the adaptors in this crate are built from, for writing
+// adaptors outside of it that behave the same way
+//
+// `FnWrapper` turns an infallible closure into one that returns `Result`, so
+// that only the `try_` variant of a method needs an implementation.
+// `LoopState` is what `try_fold` implementations break out of a loop with,
+// keeping the errors of the underlying iterator and of a closure apart, and
+// `MapResult::wrap` lets `?` inside such a loop produce a `LoopState`
+//
+// the `forward_*` macros expand to a method of `TryIterator` or
+// `DoubleEndedTryIterator` that calls the same method of the iterator in a
+// field of the adaptor, and go inside the trait impl. the field is a name or
+// a tuple index. `forward_nth!` and `forward_nth_back!` generate `try_nth` and
+// `try_nth_back`, which the provided `nth` and `nth_back` and the adaptors of
+// this crate go through, so that skipping stays as fast as it is in the
+// underlying iterator
+pub use crate::{
+    fn_wrapper::FnWrapper,
+    loopstate::{LoopBreak, LoopState, MapError, MapResult},
+};
+
+pub mod size_hint {
+    pub use crate::size_hint::{
+        add, min, mul, sub, SizeHint, SizeHintAdd, SizeHintExt, SizeHintMin, SizeHintMul,
+        SizeHintSub, ZERO,
+    };
+}
+
+pub use crate::{forward_count, forward_last, forward_nth, forward_nth_back, forward_size_hint};
+
+// forwards `size_hint` to the iterator in the given field, for adaptors that
+// yield exactly one item per item of that iterator
+#[macro_export]
+macro_rules! forward_size_hint {
+    ($field:tt) => {
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            $crate::TryIterator::size_hint(&self.$field)
+        }
+    };
+}
+
+// forwards `try_nth` to the iterator in the given field, optionally mapping
+// the item. only correct if skipped items don't need to be seen by the adaptor
+#[macro_export]
+macro_rules! forward_nth {
+    ($field:tt) => {
+        $crate::forward_nth!($field, |x| x);
+    };
+    ($field:tt, $map:expr) => {
+        fn try_nth(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
+            Ok($crate::TryIterator::try_nth(&mut self.$field, n)?.map($map))
+        }
+    };
+}
+
+// like `forward_nth!`, for `try_nth_back` in a `DoubleEndedTryIterator` impl
+#[macro_export]
+macro_rules! forward_nth_back {
+    ($field:tt) => {
+        $crate::forward_nth_back!($field, |x| x);
+    };
+    ($field:tt, $map:expr) => {
+        fn try_nth_back(&mut self, n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
+            Ok($crate::DoubleEndedTryIterator::try_nth_back(&mut self.$field, n)?.map($map))
+        }
+    };
+}
+
+// forwards `count`, for adaptors that yield exactly one item per item
+#[macro_export]
+macro_rules! forward_count {
+    ($field:tt) => {
+        fn count(self) -> Result<usize, Self::Error> {
+            Ok($crate::TryIterator::count(self.$field)?)
+        }
+    };
+}
+
+// forwards `last`, optionally mapping the item, for adaptors whose last item
+// comes from the last item of the underlying iterator
+#[macro_export]
+macro_rules! forward_last {
+    ($field:tt) => {
+        $crate::forward_last!($field, |x| x);
+    };
+    ($field:tt, $map:expr) => {
+        fn last(self) -> Result<Option<Self::Item>, Self::Error> {
+            Ok($crate::TryIterator::last(self.$field)?.map($map))
+        }
+    };
+}
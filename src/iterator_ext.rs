@@ -1,5 +1,8 @@
 use super::*;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
+
 pub trait IteratorExt: Iterator {
     fn try_filter<F, R>(self, f: F) -> Filter<IteratorWrapper<Self, R::Error>, F>
     where
@@ -51,6 +54,15 @@ pub trait IteratorExt: Iterator {
         IteratorWrapper::new(self).try_flat_map(f)
     }
 
+    fn try_intersperse_with<F, R>(self, f: F) -> IntersperseWith<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut() -> R,
+        R: Try<Ok = Self::Item>,
+    {
+        IteratorWrapper::new(self).try_intersperse_with(f)
+    }
+
     fn try_take_while<F, R>(self, f: F) -> TakeWhile<IteratorWrapper<Self, R::Error>, F>
     where
         Self: Sized,
@@ -87,6 +99,150 @@ pub trait IteratorExt: Iterator {
         IteratorWrapper::new(self).try_scan(state, f)
     }
 
+    fn try_dedup_by<F, R>(self, f: F) -> DedupBy<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = bool>,
+    {
+        IteratorWrapper::new(self).try_dedup_by(f)
+    }
+
+    fn try_dedup_by_key<F, R, K>(self, f: F) -> DedupByKey<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: PartialEq,
+    {
+        IteratorWrapper::new(self).try_dedup_by_key(f)
+    }
+
+    fn try_coalesce<F, R>(self, f: F) -> Coalesce<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Ok = Result<Self::Item, (Self::Item, Self::Item)>>,
+    {
+        IteratorWrapper::new(self).try_coalesce(f)
+    }
+
+    fn try_chunk_by<F, R, K, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> ChunkBy<IteratorWrapper<Self, R::Error>, K, F, N, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: PartialEq,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        IteratorWrapper::new(self).try_chunk_by(f, init, g)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_group_by_key<F, R, K>(self, f: F) -> GroupByKey<IteratorWrapper<Self, R::Error>, K, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: PartialEq,
+    {
+        IteratorWrapper::new(self).try_group_by_key(f)
+    }
+
+    fn try_split_fold<F, R, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<IteratorWrapper<Self, R::Error>, F, N, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        IteratorWrapper::new(self).try_split_fold(f, init, g)
+    }
+
+    fn try_split_inclusive_fold<F, R, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<IteratorWrapper<Self, R::Error>, F, N, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        IteratorWrapper::new(self).try_split_inclusive_fold(f, init, g)
+    }
+
+    fn try_splitn_fold<F, R, N, B, G>(
+        self,
+        n: usize,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<IteratorWrapper<Self, R::Error>, F, N, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        IteratorWrapper::new(self).try_splitn_fold(n, f, init, g)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_split<F, R>(self, f: F) -> Split<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+    {
+        IteratorWrapper::new(self).try_split(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_split_inclusive<F, R>(self, f: F) -> Split<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+    {
+        IteratorWrapper::new(self).try_split_inclusive(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_splitn<F, R>(self, n: usize, f: F) -> Split<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+    {
+        IteratorWrapper::new(self).try_splitn(n, f)
+    }
+
+    fn try_fold_while<B, F, R>(&mut self, init: B, f: F) -> Result<FoldWhile<B>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Ok = FoldWhile<B>>,
+    {
+        IteratorWrapper::new(self).try_fold_while(init, f)
+    }
+
     fn try_find_map<F, R, T>(&mut self, f: F) -> R
     where
         Self: Sized,
@@ -161,6 +317,137 @@ pub trait IteratorExt: Iterator {
         IteratorWrapper::new(self).try_max_by_key(f)
     }
 
+    fn try_minmax_by<F, R>(self, f: F) -> Result<MinMaxResult<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_minmax_by(f)
+    }
+
+    fn try_minmax_by_key<F, R, T>(self, f: F) -> Result<MinMaxResult<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        T: Ord,
+    {
+        IteratorWrapper::new(self).try_minmax_by_key(f)
+    }
+
+    fn try_position_min_by<F, R>(self, f: F) -> Result<Option<usize>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_position_min_by(f)
+    }
+
+    fn try_position_min_by_key<F, R, T>(self, f: F) -> Result<Option<usize>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        T: Ord,
+    {
+        IteratorWrapper::new(self).try_position_min_by_key(f)
+    }
+
+    fn try_position_max_by<F, R>(self, f: F) -> Result<Option<usize>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_position_max_by(f)
+    }
+
+    fn try_position_max_by_key<F, R, T>(self, f: F) -> Result<Option<usize>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        T: Ord,
+    {
+        IteratorWrapper::new(self).try_position_max_by_key(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_min_set_by<F, R>(self, f: F) -> Result<Vec<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_min_set_by(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_min_set_by_key<F, R, K>(self, f: F) -> Result<Vec<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: Ord,
+    {
+        IteratorWrapper::new(self).try_min_set_by_key(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_max_set_by<F, R>(self, f: F) -> Result<Vec<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_max_set_by(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_max_set_by_key<F, R, K>(self, f: F) -> Result<Vec<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: Ord,
+    {
+        IteratorWrapper::new(self).try_max_set_by_key(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by<F, R>(self, f: F) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+    {
+        IteratorWrapper::new(self).try_sorted_by(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by_key<F, R, K>(self, f: F) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: Ord,
+    {
+        IteratorWrapper::new(self).try_sorted_by_key(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by_cached_key<F, R, K>(self, f: F) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        K: Ord,
+    {
+        IteratorWrapper::new(self).try_sorted_by_cached_key(f)
+    }
+
     fn try_partial_cmp_by<I, F, R>(self, other: I, f: F) -> R
     where
         Self: Sized,
@@ -194,6 +481,21 @@ pub trait IteratorExt: Iterator {
         IteratorWrapper::new(self).try_eq_by(other, f)
     }
 
+    fn try_merge_join_by<I, F, R>(
+        self,
+        other: I,
+        f: F,
+    ) -> MergeJoinBy<IteratorWrapper<Self, R::Error>, I, F>
+    where
+        Self: Sized,
+        I: TryIterator,
+        F: FnMut(&Self::Item, &I::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<I::Error>,
+    {
+        IteratorWrapper::<_, R::Error>::new(self).try_merge_join_by(other, f)
+    }
+
     fn try_is_sorted_by<F, R>(self, f: F) -> R
     where
         Self: Sized,
@@ -213,6 +515,62 @@ pub trait IteratorExt: Iterator {
         IteratorWrapper::new(self).try_is_sorted_by_key(f)
     }
 
+    fn try_counts_by<M, F, R, K>(self, f: F) -> Result<M, R::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, usize>,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = K>,
+    {
+        IteratorWrapper::new(self).try_counts_by(f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_into_group_map_by<M, F, R, K>(self, f: F) -> Result<M, R::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, Vec<Self::Item>>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+    {
+        IteratorWrapper::new(self).try_into_group_map_by(f)
+    }
+
+    fn try_into_grouping_map_by<F, R, K>(
+        self,
+        f: F,
+    ) -> GroupingMapBy<IteratorWrapper<Self, R::Error>, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+    {
+        IteratorWrapper::new(self).try_into_grouping_map_by(f)
+    }
+
+    fn try_unique_by<S, F, R, K>(self, f: F) -> UniqueBy<IteratorWrapper<Self, R::Error>, F, S>
+    where
+        Self: Sized,
+        S: KeySet<K>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+    {
+        IteratorWrapper::new(self).try_unique_by(f)
+    }
+
+    fn try_duplicates_by<M, F, R, K>(
+        self,
+        f: F,
+    ) -> DuplicatesBy<IteratorWrapper<Self, R::Error>, F, M>
+    where
+        Self: Sized,
+        M: GroupMap<K, bool>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+    {
+        IteratorWrapper::new(self).try_duplicates_by(f)
+    }
+
     fn try_partition<B, F, R>(self, f: F) -> Result<(B, B), R::Error>
     where
         Self: Sized,
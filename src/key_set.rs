@@ -0,0 +1,34 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+// the set that the uniqueness adaptors use to remember the keys they have
+// seen. implemented for `HashSet` and `BTreeSet`, and by any other set that
+// should be used instead
+pub trait KeySet<K>: Default {
+    // returns `true` if the key wasn't in the set yet
+    fn insert(&mut self, key: K) -> bool;
+}
+
+#[cfg(feature = "std")]
+impl<K, S> KeySet<K> for HashSet<K, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn insert(&mut self, key: K) -> bool {
+        HashSet::insert(self, key)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K> KeySet<K> for BTreeSet<K>
+where K: Ord
+{
+    fn insert(&mut self, key: K) -> bool {
+        BTreeSet::insert(self, key)
+    }
+}
@@ -0,0 +1,35 @@
+// when there are too many items, `actual` is the number of items that were
+// read up to and including the first one that shouldn't be there
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LengthError<E> {
+    Mismatch(LengthMismatch),
+    IterError(E),
+}
+
+impl<E> LengthError<E> {
+    pub fn mismatch(self) -> Option<LengthMismatch> {
+        match self {
+            Self::Mismatch(m) => Some(m),
+            Self::IterError(_) => None,
+        }
+    }
+
+    pub fn iter_error(self) -> Option<E> {
+        match self {
+            Self::Mismatch(_) => None,
+            Self::IterError(e) => Some(e),
+        }
+    }
+}
+
+impl<E> From<E> for LengthError<E> {
+    fn from(e: E) -> Self {
+        Self::IterError(e)
+    }
+}
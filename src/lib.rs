@@ -1,21 +1,74 @@
 #![no_std]
-#![feature(try_trait, fn_traits, never_type, unboxed_closures, try_blocks, specialization)]
+#![feature(
+    try_trait,
+    fn_traits,
+    never_type,
+    unboxed_closures,
+    try_blocks,
+    specialization,
+    extend_one,
+    control_flow_enum
+)]
+#![allow(incomplete_features)]
+#![allow(
+    clippy::match_bool,
+    clippy::option_option,
+    clippy::type_complexity,
+    clippy::wrong_self_convention
+)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod adaptors;
+mod arithmetic;
+mod array_buffer;
+mod bounded;
+mod collect_array;
+mod collect_map;
+mod either_or_both;
+mod exactly_one;
 mod fn_wrapper;
+mod fold_while;
+mod grouping_map;
 mod iterator_ext;
 mod iterator_wrapper;
+mod key_set;
+mod length_mismatch;
 mod loopstate;
+mod minmax;
 mod size_hint;
 mod traits;
 
+pub mod impl_helpers;
+pub mod reducers;
+pub mod stats;
+#[cfg(feature = "testing")]
+pub mod testing;
+
 pub use adaptors::*;
+pub use arithmetic::{ArithmeticError, SaturatingSum, TryProduct, TrySum};
+pub use bounded::{BoundedCollection, CapacityError};
+pub use collect_array::{CollectArrayError, PartialArray};
+pub use collect_map::{CollectMapError, DuplicateKey};
+pub use either_or_both::EitherOrBoth;
+pub use exactly_one::{AtMostOneError, ExactlyOneError, TooMany};
+pub use fold_while::FoldWhile;
+pub use grouping_map::{GroupMap, GroupingMap, GroupingMapBy, KeyBy};
 pub use iterator_ext::IteratorExt;
+pub use key_set::KeySet;
+pub use length_mismatch::{LengthError, LengthMismatch};
+pub use loopstate::LoopState;
+pub use minmax::MinMaxResult;
+pub use reducers::Reducer;
 pub use traits::*;
 
+use array_buffer::ArrayBuffer;
 use fn_wrapper::FnWrapper;
 use iterator_wrapper::IteratorWrapper;
-use loopstate::{LoopState, MapResult};
+use loopstate::MapResult;
 use size_hint::SizeHintExt;
 
 use core::{
@@ -68,3 +121,9 @@ where
         Ordering::Greater => x,
     })
 }
+
+#[cfg(feature = "alloc")]
+fn push<T>(mut v: alloc::vec::Vec<T>, x: T) -> alloc::vec::Vec<T> {
+    v.push(x);
+    v
+}
@@ -1,5 +1,6 @@
 use super::*;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum LoopState<C, B, I, M> {
     Continue(C),
     Break(B),
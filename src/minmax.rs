@@ -0,0 +1,84 @@
+use super::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MinMaxResult<T> {
+    NoElements,
+    OneElement(T),
+    MinMax(T, T),
+}
+
+impl<T> MinMaxResult<T> {
+    pub fn into_option(self) -> Option<(T, T)>
+    where T: Clone {
+        match self {
+            Self::NoElements => None,
+            Self::OneElement(x) => Some((x.clone(), x)),
+            Self::MinMax(x, y) => Some((x, y)),
+        }
+    }
+
+    pub fn map<F, U>(self, mut f: F) -> MinMaxResult<U>
+    where F: FnMut(T) -> U {
+        match self {
+            Self::NoElements => MinMaxResult::NoElements,
+            Self::OneElement(x) => MinMaxResult::OneElement(f(x)),
+            Self::MinMax(x, y) => MinMaxResult::MinMax(f(x), f(y)),
+        }
+    }
+}
+
+// items are compared in pairs, after which only the smaller one is compared
+// to the minimum and only the larger one to the maximum, for a total of about
+// 1.5 comparisons per item. the minimum is the first smallest item and the
+// maximum is the last largest item
+pub(crate) fn try_minmax_by<I, F, R>(
+    mut iter: I,
+    mut f: F,
+) -> Result<MinMaxResult<I::Item>, R::Error>
+where
+    I: TryIterator,
+    F: FnMut(&I::Item, &I::Item) -> R,
+    R: Try<Ok = Ordering>,
+    R::Error: From<I::Error>,
+{
+    let mut lt =
+        |x: &I::Item, y: &I::Item| -> Result<bool, R::Error> { Ok(f(x, y)? == Ordering::Less) };
+    let mut pending = None;
+    let result = iter.try_fold(MinMaxResult::NoElements, |result, x| {
+        Ok::<_, R::Error>(match result {
+            MinMaxResult::NoElements => MinMaxResult::OneElement(x),
+            MinMaxResult::OneElement(y) => match lt(&x, &y)? {
+                true => MinMaxResult::MinMax(x, y),
+                false => MinMaxResult::MinMax(y, x),
+            },
+            MinMaxResult::MinMax(min, max) => match pending.take() {
+                None => {
+                    pending = Some(x);
+                    MinMaxResult::MinMax(min, max)
+                }
+                Some(y) => {
+                    let (small, large) = match lt(&x, &y)? {
+                        true => (x, y),
+                        false => (y, x),
+                    };
+                    let min = if lt(&small, &min)? { small } else { min };
+                    let max = if lt(&large, &max)? { max } else { large };
+                    MinMaxResult::MinMax(min, max)
+                }
+            },
+        })
+    })?;
+
+    Ok(match (result, pending) {
+        (MinMaxResult::MinMax(min, max), Some(x)) => {
+            if lt(&x, &min)? {
+                MinMaxResult::MinMax(x, max)
+            } else if lt(&x, &max)? {
+                MinMaxResult::MinMax(min, max)
+            } else {
+                MinMaxResult::MinMax(min, x)
+            }
+        }
+        (result, _) => result,
+    })
+}
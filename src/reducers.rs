@@ -0,0 +1,364 @@
+use super::*;
+
+use core::{
+    iter,
+    ops::{Add, Mul},
+};
+
+// a fold split into its parts, so that several of them can share one pass
+// over an iterator. tuples of reducers feed every item to each of them
+pub trait Reducer<T> {
+    type State;
+    type Output;
+
+    fn init(&mut self) -> Self::State;
+    fn step(&mut self, state: Self::State, x: T) -> Self::State;
+    fn finish(self, state: Self::State) -> Self::Output;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Count;
+
+impl<T> Reducer<T> for Count {
+    type State = usize;
+    type Output = usize;
+
+    fn init(&mut self) -> usize {
+        0
+    }
+
+    fn step(&mut self, n: usize, _: T) -> usize {
+        n + 1
+    }
+
+    fn finish(self, n: usize) -> usize {
+        n
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sum;
+
+impl<T> Reducer<T> for Sum
+where T: Add<Output = T> + iter::Sum<T>
+{
+    type State = Option<T>;
+    type Output = T;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, acc: Option<T>, x: T) -> Option<T> {
+        Some(match acc {
+            None => x,
+            Some(acc) => acc + x,
+        })
+    }
+
+    // `Sum` knows what the sum of no items is
+    fn finish(self, acc: Option<T>) -> T {
+        acc.unwrap_or_else(|| iter::empty().sum())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Product;
+
+impl<T> Reducer<T> for Product
+where T: Mul<Output = T> + iter::Product<T>
+{
+    type State = Option<T>;
+    type Output = T;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, acc: Option<T>, x: T) -> Option<T> {
+        Some(match acc {
+            None => x,
+            Some(acc) => acc * x,
+        })
+    }
+
+    fn finish(self, acc: Option<T>) -> T {
+        acc.unwrap_or_else(|| iter::empty().product())
+    }
+}
+
+// the first minimum, like `TryIterator::min`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Min;
+
+impl Min {
+    pub fn by<F>(f: F) -> MinBy<F> {
+        MinBy { f }
+    }
+
+    pub fn by_key<F>(f: F) -> MinByKey<F> {
+        MinByKey { f }
+    }
+}
+
+impl<T> Reducer<T> for Min
+where T: Ord
+{
+    type State = Option<T>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, min: Option<T>, x: T) -> Option<T> {
+        MinBy { f: T::cmp }.step(min, x)
+    }
+
+    fn finish(self, min: Option<T>) -> Option<T> {
+        min
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MinBy<F> {
+    f: F,
+}
+
+impl<T, F> Reducer<T> for MinBy<F>
+where F: FnMut(&T, &T) -> Ordering
+{
+    type State = Option<T>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, min: Option<T>, x: T) -> Option<T> {
+        Some(match min {
+            Some(min) if (self.f)(&min, &x) != Ordering::Greater => min,
+            _ => x,
+        })
+    }
+
+    fn finish(self, min: Option<T>) -> Option<T> {
+        min
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MinByKey<F> {
+    f: F,
+}
+
+impl<T, F, K> Reducer<T> for MinByKey<F>
+where
+    F: FnMut(&T) -> K,
+    K: Ord,
+{
+    type State = Option<(K, T)>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<(K, T)> {
+        None
+    }
+
+    fn step(&mut self, min: Option<(K, T)>, x: T) -> Option<(K, T)> {
+        let key = (self.f)(&x);
+        Some(match min {
+            Some(min) if min.0 <= key => min,
+            _ => (key, x),
+        })
+    }
+
+    fn finish(self, min: Option<(K, T)>) -> Option<T> {
+        min.map(|(_, x)| x)
+    }
+}
+
+// the last maximum, like `TryIterator::max`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Max;
+
+impl Max {
+    pub fn by<F>(f: F) -> MaxBy<F> {
+        MaxBy { f }
+    }
+
+    pub fn by_key<F>(f: F) -> MaxByKey<F> {
+        MaxByKey { f }
+    }
+}
+
+impl<T> Reducer<T> for Max
+where T: Ord
+{
+    type State = Option<T>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, max: Option<T>, x: T) -> Option<T> {
+        MaxBy { f: T::cmp }.step(max, x)
+    }
+
+    fn finish(self, max: Option<T>) -> Option<T> {
+        max
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MaxBy<F> {
+    f: F,
+}
+
+impl<T, F> Reducer<T> for MaxBy<F>
+where F: FnMut(&T, &T) -> Ordering
+{
+    type State = Option<T>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<T> {
+        None
+    }
+
+    fn step(&mut self, max: Option<T>, x: T) -> Option<T> {
+        Some(match max {
+            Some(max) if (self.f)(&max, &x) == Ordering::Greater => max,
+            _ => x,
+        })
+    }
+
+    fn finish(self, max: Option<T>) -> Option<T> {
+        max
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MaxByKey<F> {
+    f: F,
+}
+
+impl<T, F, K> Reducer<T> for MaxByKey<F>
+where
+    F: FnMut(&T) -> K,
+    K: Ord,
+{
+    type State = Option<(K, T)>;
+    type Output = Option<T>;
+
+    fn init(&mut self) -> Option<(K, T)> {
+        None
+    }
+
+    fn step(&mut self, max: Option<(K, T)>, x: T) -> Option<(K, T)> {
+        let key = (self.f)(&x);
+        Some(match max {
+            Some(max) if max.0 > key => max,
+            _ => (key, x),
+        })
+    }
+
+    fn finish(self, max: Option<(K, T)>) -> Option<T> {
+        max.map(|(_, x)| x)
+    }
+}
+
+// splits the items into the ones that satisfy the predicate and the ones that
+// don't, like `TryIterator::partition`
+pub struct Partition<B, F> {
+    f: F,
+    _marker: PhantomData<B>,
+}
+
+impl<B, F> Partition<B, F> {
+    pub fn new(f: F) -> Self {
+        Self { f, _marker: PhantomData }
+    }
+}
+
+impl<B, F> Clone for Partition<B, F>
+where F: Clone
+{
+    fn clone(&self) -> Self {
+        Self::new(self.f.clone())
+    }
+}
+
+impl<B, F> Debug for Partition<B, F>
+where F: Debug
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Partition").field("f", &self.f).finish()
+    }
+}
+
+impl<T, B, F> Reducer<T> for Partition<B, F>
+where
+    B: Default + Extend<T>,
+    F: FnMut(&T) -> bool,
+{
+    type State = (B, B);
+    type Output = (B, B);
+
+    fn init(&mut self) -> (B, B) {
+        (B::default(), B::default())
+    }
+
+    fn step(&mut self, (mut a, mut b): (B, B), x: T) -> (B, B) {
+        if (self.f)(&x) {
+            a.extend_one(x);
+        } else {
+            b.extend_one(x);
+        }
+        (a, b)
+    }
+
+    fn finish(self, state: (B, B)) -> (B, B) {
+        state
+    }
+}
+
+// every reducer but the last one gets a clone of each item
+macro_rules! tuple_impls {
+    ($(($($r:ident $a:ident $s:ident),*; $last:ident $last_a:ident $last_s:ident))*) => {$(
+        impl<T, $($r,)* $last> Reducer<T> for ($($r,)* $last,)
+        where
+            T: Clone,
+            $($r: Reducer<T>,)*
+            $last: Reducer<T>,
+        {
+            type State = ($($r::State,)* $last::State,);
+            type Output = ($($r::Output,)* $last::Output,);
+
+            fn init(&mut self) -> Self::State {
+                let ($($a,)* $last_a,) = self;
+                ($($a.init(),)* $last_a.init(),)
+            }
+
+            fn step(&mut self, ($($s,)* $last_s,): Self::State, x: T) -> Self::State {
+                let ($($a,)* $last_a,) = self;
+                ($($a.step($s, x.clone()),)* $last_a.step($last_s, x),)
+            }
+
+            fn finish(self, ($($s,)* $last_s,): Self::State) -> Self::Output {
+                let ($($a,)* $last_a,) = self;
+                ($($a.finish($s),)* $last_a.finish($last_s),)
+            }
+        }
+    )*};
+}
+
+tuple_impls! {
+    (; A a sa)
+    (A a sa; B b sb)
+    (A a sa, B b sb; C c sc)
+    (A a sa, B b sb, C c sc; D d sd)
+    (A a sa, B b sb, C c sc, D d sd; E e se)
+    (A a sa, B b sb, C c sc, D d sd, E e se; G g sg)
+    (A a sa, B b sb, C c sc, D d sd, E e se, G g sg; H h sh)
+    (A a sa, B b sb, C c sc, D d sd, E e se, G g sg, H h sh; J j sj)
+}
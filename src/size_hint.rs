@@ -1,6 +1,6 @@
 use super::*;
 
-type SizeHint = (usize, Option<usize>);
+pub type SizeHint = (usize, Option<usize>);
 
 pub const ZERO: SizeHint = (0, Some(0));
 
@@ -48,7 +48,7 @@ impl SizeHintSub for SizeHint {
 
 impl SizeHintSub for usize {
     fn sub((lower, upper): SizeHint, n: usize) -> SizeHint {
-        (lower.saturating_add(n), try { upper?.saturating_sub(n) })
+        (lower.saturating_sub(n), try { upper?.saturating_sub(n) })
     }
 }
 
@@ -57,6 +57,27 @@ where T: SizeHintSub {
     T::sub(size_hint, x)
 }
 
+pub trait SizeHintMul {
+    fn mul(size_hint: SizeHint, x: Self) -> SizeHint;
+}
+
+impl SizeHintMul for SizeHint {
+    fn mul((x_lower, x_upper): SizeHint, (y_lower, y_upper): SizeHint) -> SizeHint {
+        (x_lower.saturating_mul(y_lower), try { x_upper?.checked_mul(y_upper?)? })
+    }
+}
+
+impl SizeHintMul for usize {
+    fn mul((lower, upper): SizeHint, n: usize) -> SizeHint {
+        (lower.saturating_mul(n), try { upper?.checked_mul(n)? })
+    }
+}
+
+pub fn mul<T>(size_hint: SizeHint, x: T) -> SizeHint
+where T: SizeHintMul {
+    T::mul(size_hint, x)
+}
+
 pub trait SizeHintMin {
     fn min(size_hint: SizeHint, x: Self) -> SizeHint;
 }
@@ -89,3 +110,54 @@ pub fn min<T>(size_hint: SizeHint, x: T) -> SizeHint
 where T: SizeHintMin {
     T::min(size_hint, x)
 }
+
+pub fn div((lower, upper): SizeHint, n: usize) -> SizeHint {
+    (lower / n, upper.map(|x| x / n))
+}
+
+#[cfg(feature = "alloc")]
+pub fn div_ceil((lower, upper): SizeHint, n: usize) -> SizeHint {
+    let div_ceil = |x: usize| x / n + (x % n != 0) as usize;
+    (div_ceil(lower), upper.map(div_ceil))
+}
+
+#[cfg(feature = "alloc")]
+pub fn checked_binomial(mut n: usize, k: usize) -> Option<usize> {
+    if n < k {
+        return Some(0);
+    }
+    // multiply and divide in turns to keep the intermediate results small
+    let k = cmp::min(k, n - k);
+    let mut c: usize = 1;
+    for i in 1..=k {
+        c = (c / i).checked_mul(n)?.checked_add((c % i).checked_mul(n)? / i)?;
+        n -= 1;
+    }
+    Some(c)
+}
+
+// the fewest items left in an adaptor that yields `f(n)` items once its
+// source produced `n` items, when it has read `read` items and the lower
+// bound of the source, including those, is `lower`. any of the items that
+// haven't been read yet can turn out to be an error, which is yielded in
+// place of the items it would have added. `f` has to grow faster the more
+// items there are, so the fewest are left when the errors start right where
+// it grows by at least one per item
+#[cfg(feature = "alloc")]
+pub fn lower_with_errors<F>(read: usize, lower: usize, f: F) -> usize
+where F: Fn(usize) -> Option<usize> {
+    let grows = |n: usize| match (f(n), f(n + 1)) {
+        (Some(x), Some(y)) => y > x,
+        _ => true,
+    };
+    let (mut start, mut end) = (read, cmp::max(read, lower));
+    while start < end {
+        let mid = start + (end - start) / 2;
+        if grows(mid) {
+            end = mid;
+        } else {
+            start = mid + 1;
+        }
+    }
+    f(start).map_or(usize::max_value(), |x| x.saturating_add(lower.saturating_sub(start)))
+}
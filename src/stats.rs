@@ -0,0 +1,9 @@
+mod histogram;
+mod mean_variance;
+#[cfg(feature = "alloc")]
+mod percentiles;
+
+pub use histogram::Histogram;
+pub use mean_variance::MeanVariance;
+#[cfg(feature = "alloc")]
+pub use percentiles::Percentiles;
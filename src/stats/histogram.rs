@@ -0,0 +1,100 @@
+use crate::Reducer;
+
+// counts values in `N` equally wide buckets spanning `min..max`. values
+// outside of that range, as well as NaN, are only counted as out of range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Histogram<const N: usize> {
+    min: f64,
+    max: f64,
+    buckets: [u64; N],
+    below: u64,
+    above: u64,
+}
+
+impl<const N: usize> Histogram<N> {
+    pub fn new(min: f64, max: f64) -> Self {
+        assert!(N != 0, "a histogram needs at least one bucket");
+        assert!(min < max, "the range of a histogram must not be empty");
+        Self { min, max, buckets: [0; N], below: 0, above: 0 }
+    }
+
+    pub fn push(&mut self, x: f64) {
+        if x < self.min {
+            self.below += 1;
+        } else if x < self.max {
+            let i = ((x - self.min) / (self.max - self.min) * N as f64) as usize;
+            // rounding can put values just below `max` out of bounds
+            self.buckets[i.min(N - 1)] += 1;
+        } else {
+            self.above += 1;
+        }
+    }
+
+    // panics if the histograms don't have the exact same range
+    #[allow(clippy::float_cmp)]
+    pub fn merge(&mut self, other: &Self) {
+        assert!(self.min == other.min && self.max == other.max, "histogram ranges differ");
+        for (a, b) in self.buckets.iter_mut().zip(&other.buckets) {
+            *a += b;
+        }
+        self.below += other.below;
+        self.above += other.above;
+    }
+
+    pub fn buckets(&self) -> &[u64; N] {
+        &self.buckets
+    }
+
+    pub fn bucket_range(&self, i: usize) -> (f64, f64) {
+        assert!(i < N, "bucket index out of bounds");
+        let width = (self.max - self.min) / N as f64;
+        let start = self.min + width * i as f64;
+        let end = if i == N - 1 { self.max } else { start + width };
+        (start, end)
+    }
+
+    pub fn below(&self) -> u64 {
+        self.below
+    }
+
+    // includes NaN
+    pub fn above(&self) -> u64 {
+        self.above
+    }
+
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum::<u64>() + self.below + self.above
+    }
+}
+
+impl<const N: usize> Extend<f64> for Histogram<N> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = f64> {
+        iter.into_iter().for_each(|x| self.push(x))
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a f64> for Histogram<N> {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = &'a f64> {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl<const N: usize> Reducer<f64> for Histogram<N> {
+    type State = Self;
+    type Output = Self;
+
+    fn init(&mut self) -> Self {
+        *self
+    }
+
+    fn step(&mut self, mut state: Self, x: f64) -> Self {
+        state.push(x);
+        state
+    }
+
+    fn finish(self, state: Self) -> Self {
+        state
+    }
+}
@@ -0,0 +1,103 @@
+use crate::Reducer;
+
+// running mean and variance using Welford's algorithm, which doesn't suffer
+// from the cancellation that summing squares does
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MeanVariance {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl MeanVariance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    // combines the statistics of two disjoint sets of values
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let weight = self.count as f64 * other.count as f64 / count as f64;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * weight * delta;
+        self.count = count;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            _ => Some(self.mean),
+        }
+    }
+
+    pub fn variance(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            n => Some(self.m2 / n as f64),
+        }
+    }
+
+    pub fn sample_variance(&self) -> Option<f64> {
+        match self.count {
+            0 | 1 => None,
+            n => Some(self.m2 / (n - 1) as f64),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn sample_std_dev(&self) -> Option<f64> {
+        self.sample_variance().map(f64::sqrt)
+    }
+}
+
+impl Extend<f64> for MeanVariance {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = f64> {
+        iter.into_iter().for_each(|x| self.push(x))
+    }
+}
+
+impl<'a> Extend<&'a f64> for MeanVariance {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = &'a f64> {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl Reducer<f64> for MeanVariance {
+    type State = Self;
+    type Output = Self;
+
+    fn init(&mut self) -> Self {
+        *self
+    }
+
+    fn step(&mut self, mut state: Self, x: f64) -> Self {
+        state.push(x);
+        state
+    }
+
+    fn finish(self, state: Self) -> Self {
+        state
+    }
+}
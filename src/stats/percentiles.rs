@@ -0,0 +1,157 @@
+use crate::Reducer;
+use alloc::vec::Vec;
+use core::{
+    cmp::{self, Ordering},
+    mem,
+};
+
+// approximate quantiles in bounded memory. values are collected in a stack of
+// compactors where every value at level `h` stands for `2^h` original values.
+// whenever a level holds `capacity` values it is sorted and every other value
+// moves up a level, alternating between the odd and even positions so that
+// the errors tend to cancel out. the rank error grows with `log(n / capacity)
+// / capacity`, and results are exact until `capacity` values have been seen.
+// the minimum and maximum are always exact
+#[derive(Clone, Debug)]
+pub struct Percentiles {
+    capacity: usize,
+    levels: Vec<Level>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Level {
+    values: Vec<f64>,
+    odd: bool,
+}
+
+impl Percentiles {
+    pub fn new() -> Self {
+        Self::with_capacity(256)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity >= 2, "capacity must be at least 2");
+        Self { capacity, levels: Vec::new(), count: 0, min: f64::INFINITY, max: f64::NEG_INFINITY }
+    }
+
+    // NaN is ignored
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        if self.levels.is_empty() {
+            self.levels.push(Level::default());
+        }
+        self.count += 1;
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+        self.levels[0].values.push(x);
+        let mut h = 0;
+        while self.levels[h].values.len() >= self.capacity {
+            self.compact(h);
+            h += 1;
+        }
+    }
+
+    fn compact(&mut self, h: usize) {
+        if h + 1 == self.levels.len() {
+            self.levels.push(Level::default());
+        }
+        let level = &mut self.levels[h];
+        level.values.sort_unstable_by(compare);
+        // an odd value out stays behind so that the total weight is unchanged
+        let leftover = match level.values.len() % 2 {
+            0 => None,
+            _ => level.values.pop(),
+        };
+        let offset = level.odd as usize;
+        level.odd = !level.odd;
+        let promoted: Vec<f64> = level.values.drain(..).skip(offset).step_by(2).collect();
+        level.values.extend(leftover);
+        self.levels[h + 1].values.extend(promoted);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    // the approximate value at quantile `q`, which must be between 0 and 1
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        assert!((0.0..=1.0).contains(&q), "quantile must be between 0 and 1");
+        // `ceil` isn't available without `std`
+        let target = q * self.count as f64;
+        let rank = cmp::max(1, target as u64 + ((target as u64 as f64) < target) as u64);
+        match self.count {
+            0 => return None,
+            n if rank == n => return Some(self.max),
+            _ if rank == 1 => return Some(self.min),
+            _ => {}
+        }
+
+        let mut weighted: Vec<(f64, u64)> = self
+            .levels
+            .iter()
+            .enumerate()
+            .flat_map(|(h, level)| level.values.iter().map(move |&x| (x, 1 << h)))
+            .collect();
+        weighted.sort_unstable_by(|a, b| compare(&a.0, &b.0));
+        let mut seen = 0;
+        weighted.into_iter().find_map(|(x, weight)| {
+            seen += weight;
+            if seen >= rank { Some(x) } else { None }
+        })
+    }
+
+    // the approximate value at percentile `p`, which must be between 0 and 100
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        self.quantile(p / 100.0)
+    }
+}
+
+impl Default for Percentiles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<f64> for Percentiles {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = f64> {
+        iter.into_iter().for_each(|x| self.push(x))
+    }
+}
+
+impl<'a> Extend<&'a f64> for Percentiles {
+    fn extend<I>(&mut self, iter: I)
+    where I: IntoIterator<Item = &'a f64> {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
+impl Reducer<f64> for Percentiles {
+    type State = Self;
+    type Output = Self;
+
+    // the reducer itself is the initial state. it is moved out rather than
+    // cloned, leaving an empty sketch behind
+    fn init(&mut self) -> Self {
+        mem::replace(self, Self::with_capacity(self.capacity))
+    }
+
+    fn step(&mut self, mut state: Self, x: f64) -> Self {
+        state.push(x);
+        state
+    }
+
+    fn finish(self, state: Self) -> Self {
+        state
+    }
+}
+
+// NaN is never stored, so this never fails
+fn compare(a: &f64, b: &f64) -> Ordering {
+    a.partial_cmp(b).unwrap()
+}
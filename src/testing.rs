@@ -0,0 +1,11 @@
+mod check;
+mod fault_injector;
+mod script;
+#[cfg(feature = "proptest")]
+mod strategy;
+
+pub use check::{check_double_ended, check_try_iterator};
+pub use fault_injector::FaultInjector;
+pub use script::{check_model, Script, ScriptedIter, SizeHints, Step};
+#[cfg(feature = "proptest")]
+pub use strategy::{script, size_hints, wild_script};
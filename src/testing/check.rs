@@ -0,0 +1,386 @@
+use crate::{DoubleEndedTryIterator, ExactSizeTryIterator, FusedTryIterator, TryIterator};
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+type Event<T, E> = Result<T, E>;
+
+// iterators that run for longer than this are assumed not to end
+const MAX_EVENTS: usize = 10_000;
+
+// checks that every method of the iterators returned by `make_iter` agrees
+// with calling `next` repeatedly. `make_iter` has to return the same
+// sequence of items and errors every time it is called. errors have to
+// surface at the same position no matter which method reads them, and
+// iteration has to resume after them just like it does with `next`. panics on
+// the first disagreement
+pub fn check_try_iterator<I, F>(mut make_iter: F)
+where
+    F: FnMut() -> I,
+    I: TryIterator,
+    I::Item: PartialEq + Debug,
+    I::Error: PartialEq + Debug,
+{
+    let reference = reference(&mut make_iter(), I::next, "next");
+    check_size_hints(&mut make_iter(), &reference, I::next, "next");
+    check_fused(&mut make_iter(), I::next, "next");
+
+    let mut iter = make_iter();
+    let events = collect(|| {
+        let mut items = Vec::new();
+        let result = iter.try_fold((), |(), x| {
+            items.push(Ok(x));
+            Ok(())
+        });
+        (items, result)
+    });
+    assert_eq!(events, reference, "`try_fold` disagrees with `next`");
+
+    let mut items = Vec::new();
+    let result = make_iter().fold((), |(), x| items.push(Ok(x)));
+    assert_eq!(
+        refs(&until_error(items, result)),
+        first_pass(&reference),
+        "`fold` disagrees with `next`"
+    );
+
+    let expected = match first_error(&reference) {
+        Some(e) => Err(e),
+        None => Ok(reference.len()),
+    };
+    assert_eq!(make_iter().count().as_ref().map(|&n| n), expected, "`count` disagrees with `next`");
+
+    let expected = match first_error(&reference) {
+        Some(e) => Err(e),
+        None => Ok(reference.last().map(|x| x.as_ref().ok().unwrap())),
+    };
+    assert_eq!(
+        make_iter().last().as_ref().map(Option::as_ref),
+        expected,
+        "`last` disagrees with `next`"
+    );
+
+    for n in 0..4 {
+        let mut iter = make_iter();
+        let events = collect_nth(|| iter.try_nth(n));
+        assert_eq!(
+            nth_refs(&events),
+            model_nth(&reference, n),
+            "`try_nth({})` disagrees with `next`",
+            n
+        );
+
+        let mut iter = make_iter();
+        let events = collect_nth(|| iter.nth(n).map(|x| x.ok_or(0)));
+        assert_eq!(
+            strip_remaining(nth_refs(&events)),
+            strip_remaining(model_nth(&reference, n)),
+            "`nth({})` disagrees with `next`",
+            n
+        );
+    }
+}
+
+// does everything `check_try_iterator` does, and checks the back of the
+// iterator in the same way against calling `next_back` repeatedly. if the
+// iterator doesn't fail from either end, iterating from both ends has to meet
+// in the middle. the ends are allowed to fail differently, reading from the
+// back of `take` for instance goes through items that the front never reads
+pub fn check_double_ended<I, F>(mut make_iter: F)
+where
+    F: FnMut() -> I,
+    I: DoubleEndedTryIterator,
+    I::Item: PartialEq + Debug,
+    I::Error: PartialEq + Debug,
+{
+    check_try_iterator(&mut make_iter);
+
+    let front = reference(&mut make_iter(), I::next, "next");
+    let reference = reference(&mut make_iter(), I::next_back, "next_back");
+    check_size_hints(&mut make_iter(), &reference, I::next_back, "next_back");
+    check_fused(&mut make_iter(), I::next_back, "next_back");
+
+    let mut iter = make_iter();
+    let events = collect(|| {
+        let mut items = Vec::new();
+        let result = iter.try_rfold((), |(), x| {
+            items.push(Ok(x));
+            Ok(())
+        });
+        (items, result)
+    });
+    assert_eq!(events, reference, "`try_rfold` disagrees with `next_back`");
+
+    let mut items = Vec::new();
+    let result = make_iter().rfold((), |(), x| items.push(Ok(x)));
+    assert_eq!(
+        refs(&until_error(items, result)),
+        first_pass(&reference),
+        "`rfold` disagrees with `next_back`"
+    );
+
+    for n in 0..4 {
+        let mut iter = make_iter();
+        let events = collect_nth(|| iter.try_nth_back(n));
+        assert_eq!(
+            nth_refs(&events),
+            model_nth(&reference, n),
+            "`try_nth_back({})` disagrees with `next_back`",
+            n
+        );
+
+        let mut iter = make_iter();
+        let events = collect_nth(|| iter.nth_back(n).map(|x| x.ok_or(0)));
+        assert_eq!(
+            strip_remaining(nth_refs(&events)),
+            strip_remaining(model_nth(&reference, n)),
+            "`nth_back({})` disagrees with `next_back`",
+            n
+        );
+    }
+
+    if first_error(&front).is_some() || first_error(&reference).is_some() {
+        return;
+    }
+    assert!(
+        reference.iter().rev().eq(&front),
+        "`next_back` doesn't yield the items of `next` in reverse order"
+    );
+
+    // take `k` items from the front and the rest from the back
+    let len = front.len();
+    let step = core::cmp::max(1, len / 16);
+    for k in (0..=len).step_by(step) {
+        let mut iter = make_iter();
+        for (i, expected) in front[..k].iter().enumerate() {
+            let x = iter.next().map_err(|e| ok_or_panic(e, "next")).unwrap();
+            assert_eq!(x.as_ref(), expected.as_ref().ok(), "`next` disagrees after {} items", i);
+        }
+        for expected in front[k..].iter().rev() {
+            let x = iter.next_back().map_err(|e| ok_or_panic(e, "next_back")).unwrap();
+            assert_eq!(
+                x.as_ref(),
+                expected.as_ref().ok(),
+                "`next_back` disagrees after {} items were taken from the front",
+                k
+            );
+        }
+        assert!(
+            iter.next_back().map_err(|e| ok_or_panic(e, "next_back")).unwrap().is_none(),
+            "`next_back` yields items that `next` already yielded"
+        );
+        assert!(
+            iter.next().map_err(|e| ok_or_panic(e, "next")).unwrap().is_none(),
+            "`next` yields items that `next_back` already yielded"
+        );
+    }
+}
+
+pub(super) fn reference<I, N>(
+    iter: &mut I,
+    mut next: N,
+    name: &str,
+) -> Vec<Event<I::Item, I::Error>>
+where
+    I: TryIterator,
+    N: FnMut(&mut I) -> Result<Option<I::Item>, I::Error>,
+{
+    let mut events = Vec::new();
+    loop {
+        assert!(events.len() < MAX_EVENTS, "`{}` didn't finish after {} calls", name, MAX_EVENTS);
+        match next(iter) {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+// the items left after each event, which `size_hint` has to admit. an error
+// can take the place of an item, like when the closure of `try_map` fails, so
+// the lower bound and `len` may count the errors that are still coming as
+// well, but nothing beyond them. once no more errors are coming, both have to
+// be exact about the items
+fn check_size_hints<I, N>(
+    iter: &mut I,
+    reference: &[Event<I::Item, I::Error>],
+    mut next: N,
+    name: &str,
+) where
+    I: TryIterator,
+    N: FnMut(&mut I) -> Result<Option<I::Item>, I::Error>,
+{
+    let mut items = reference.iter().filter(|x| x.is_ok()).count();
+    let mut errors = reference.len() - items;
+    for i in 0..=reference.len() {
+        let (lower, upper) = iter.size_hint();
+        assert!(
+            lower <= items + errors && upper.map_or(true, |upper| items <= upper),
+            "`size_hint` returned {:?} with {} items and {} errors left, after {} calls to `{}`",
+            (lower, upper),
+            items,
+            errors,
+            i,
+            name
+        );
+        if let Some(len) = iter.exact_len() {
+            assert!(
+                items <= len && len <= items + errors,
+                "`len` returned {} with {} items and {} errors left, after {} calls to `{}`",
+                len,
+                items,
+                errors,
+                i,
+                name
+            );
+        }
+        match next(iter) {
+            Ok(None) => {}
+            Ok(Some(_)) => items -= 1,
+            Err(_) => errors -= 1,
+        }
+    }
+}
+
+fn check_fused<I, N>(iter: &mut I, mut next: N, name: &str)
+where
+    I: TryIterator,
+    N: FnMut(&mut I) -> Result<Option<I::Item>, I::Error>,
+{
+    if !iter.is_fused() {
+        return;
+    }
+    while !matches!(next(iter), Ok(None)) {}
+    for _ in 0..3 {
+        assert!(matches!(next(iter), Ok(None)), "`{}` yielded something after it was done", name);
+    }
+}
+
+// keeps calling a `try_fold`-like function, which pushes the items it reads
+// and returns the error that stopped it, until it finishes
+fn collect<T, E, F>(mut f: F) -> Vec<Event<T, E>>
+where F: FnMut() -> (Vec<Event<T, E>>, Result<(), E>) {
+    let mut events = Vec::new();
+    loop {
+        assert!(events.len() < MAX_EVENTS, "iteration didn't finish after {} events", MAX_EVENTS);
+        let (items, result) = f();
+        events.extend(items);
+        match result {
+            Ok(()) => return events,
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+// the events of a consumer that stops at the first error
+fn until_error<T, E>(mut items: Vec<Event<T, E>>, result: Result<(), E>) -> Vec<Event<T, E>> {
+    if let Err(e) = result {
+        items.push(Err(e));
+    }
+    items
+}
+
+fn first_pass<T, E>(reference: &[Event<T, E>]) -> Vec<Event<&T, &E>> {
+    let end = reference.iter().position(|x| x.is_err()).map_or(reference.len(), |i| i + 1);
+    refs(&reference[..end])
+}
+
+fn refs<T, E>(events: &[Event<T, E>]) -> Vec<Event<&T, &E>> {
+    events.iter().map(Result::as_ref).collect()
+}
+
+fn first_error<T, E>(reference: &[Event<T, E>]) -> Option<&E> {
+    reference.iter().find_map(|x| x.as_ref().err())
+}
+
+type NthEvent<T, E> = Result<Result<T, usize>, E>;
+
+fn collect_nth<T, E, F>(mut f: F) -> Vec<NthEvent<T, E>>
+where F: FnMut() -> NthEvent<T, E> {
+    let mut events = Vec::new();
+    loop {
+        assert!(events.len() < MAX_EVENTS, "iteration didn't finish after {} events", MAX_EVENTS);
+        let event = f();
+        let done = matches!(event, Ok(Err(_)));
+        events.push(event);
+        if done {
+            return events;
+        }
+    }
+}
+
+fn nth_refs<T, E>(events: &[NthEvent<T, E>]) -> Vec<NthEvent<&T, &E>> {
+    events.iter().map(|x| x.as_ref().map(Result::as_ref).map(|x| x.map_err(|&n| n))).collect()
+}
+
+// what calling `try_nth(n)` repeatedly should return, given the events that
+// `next` produces. an error ends the call it happens in, and the next call
+// starts counting from zero again
+fn model_nth<T, E>(reference: &[Event<T, E>], n: usize) -> Vec<NthEvent<&T, &E>> {
+    let mut events = Vec::new();
+    let mut skip = n;
+    for event in reference {
+        match event {
+            Err(e) => {
+                events.push(Err(e));
+                skip = n;
+            }
+            Ok(_) if skip > 0 => skip -= 1,
+            Ok(x) => {
+                events.push(Ok(Ok(x)));
+                skip = n;
+            }
+        }
+    }
+    events.push(Ok(Err(skip)));
+    events
+}
+
+// `nth` doesn't say how many items were missing
+fn strip_remaining<T, E>(events: Vec<NthEvent<T, E>>) -> Vec<Result<Option<T>, E>> {
+    events.into_iter().map(|x| x.map(Result::ok)).collect()
+}
+
+fn ok_or_panic<E: Debug>(e: E, name: &str) -> ! {
+    panic!("`{}` failed with {:?} even though `next` never did", name, e)
+}
+
+trait MaybeExactSize {
+    fn exact_len(&self) -> Option<usize>;
+}
+
+impl<I> MaybeExactSize for I
+where I: TryIterator
+{
+    default fn exact_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<I> MaybeExactSize for I
+where I: ExactSizeTryIterator
+{
+    fn exact_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+trait MaybeFused {
+    fn is_fused(&self) -> bool;
+}
+
+impl<I> MaybeFused for I
+where I: TryIterator
+{
+    default fn is_fused(&self) -> bool {
+        false
+    }
+}
+
+impl<I> MaybeFused for I
+where I: FusedTryIterator
+{
+    fn is_fused(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,119 @@
+use crate::TryIterator;
+
+use alloc::vec::Vec;
+
+// makes an iterator fail at predictable points, with a clone of `error`.
+// faults at item indices replace the item with the error, faults at calls
+// leave the underlying iterator untouched so that the next call resumes
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FaultInjector<I, E> {
+    iter: I,
+    error: E,
+    faults: Faults,
+    index: usize,
+    calls: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Faults {
+    // sorted in reverse, so the next index is at the end
+    Indices(Vec<usize>),
+    Random { state: u64, one_in: u64 },
+    EveryNthCall(usize),
+}
+
+impl<I, E> FaultInjector<I, E> {
+    fn new(iter: I, error: E, faults: Faults) -> Self {
+        Self { iter, error, faults, index: 0, calls: 0 }
+    }
+
+    // replaces the items at `indices` with errors
+    pub fn at_indices<T>(iter: I, error: E, indices: T) -> Self
+    where T: IntoIterator<Item = usize> {
+        let mut indices: Vec<_> = indices.into_iter().collect();
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        indices.dedup();
+        Self::new(iter, error, Faults::Indices(indices))
+    }
+
+    // replaces each item with an error with a probability of `1 / one_in`.
+    // the same seed always picks the same indices
+    pub fn random(iter: I, error: E, seed: u64, one_in: u64) -> Self {
+        assert!(one_in != 0, "`one_in` must be positive");
+        Self::new(iter, error, Faults::Random { state: seed, one_in })
+    }
+
+    // fails every `n`th call to `next` without advancing the underlying
+    // iterator, like a transient failure that goes away when retried. `n`
+    // must be at least 2, or no call would ever get past the fault
+    pub fn every_nth_call(iter: I, error: E, n: usize) -> Self {
+        assert!(n > 1, "`n` must be at least 2");
+        Self::new(iter, error, Faults::EveryNthCall(n))
+    }
+
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    // whether the item at `self.index` should be replaced
+    fn is_fault(&mut self) -> bool {
+        match &mut self.faults {
+            Faults::Indices(indices) => {
+                if indices.last() == Some(&self.index) {
+                    indices.pop();
+                    true
+                } else {
+                    false
+                }
+            }
+            Faults::Random { state, one_in } => next_random(state) % *one_in == 0,
+            Faults::EveryNthCall(_) => false,
+        }
+    }
+}
+
+impl<I> TryIterator for FaultInjector<I, I::Error>
+where
+    I: TryIterator,
+    I::Error: Clone,
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.calls += 1;
+        if let Faults::EveryNthCall(n) = self.faults {
+            if self.calls % n == 0 {
+                return Err(self.error.clone());
+            }
+        }
+
+        let item = match self.iter.next()? {
+            None => return Ok(None),
+            Some(item) => item,
+        };
+        let fault = self.is_fault();
+        self.index += 1;
+        if fault { Err(self.error.clone()) } else { Ok(Some(item)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let lower = match &self.faults {
+            Faults::Indices(indices) => lower.saturating_sub(indices.len()),
+            Faults::Random { .. } => 0,
+            Faults::EveryNthCall(_) => lower,
+        };
+        (lower, upper)
+    }
+}
+
+// splitmix64
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
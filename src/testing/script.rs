@@ -0,0 +1,137 @@
+use super::check::{check_try_iterator, reference};
+use crate::TryIterator;
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Step<T, E> {
+    Item(T),
+    Error(E),
+    // `next` returns `Ok(None)` here, the script continues after it
+    End,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SizeHints {
+    // the number of items before the next `End`
+    Accurate,
+    Unknown,
+    // returned no matter how many items are left
+    Misleading(usize, Option<usize>),
+}
+
+// a description of what the calls to `next` of an iterator return, past the
+// last step it keeps returning `Ok(None)`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Script<T, E> {
+    pub steps: Vec<Step<T, E>>,
+    pub size_hints: SizeHints,
+}
+
+impl<T, E> Script<T, E> {
+    pub fn new(steps: Vec<Step<T, E>>) -> Self {
+        Self { steps, size_hints: SizeHints::Accurate }
+    }
+
+    pub fn with_size_hints(self, size_hints: SizeHints) -> Self {
+        Self { size_hints, ..self }
+    }
+
+    pub fn iter(&self) -> ScriptedIter<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        ScriptedIter { script: self.clone(), index: 0 }
+    }
+
+    // the items and errors up to the first `End`
+    pub fn events(&self) -> Vec<Result<T, E>>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        self.steps
+            .iter()
+            .take_while(|step| !matches!(step, Step::End))
+            .filter_map(|step| match step {
+                Step::Item(x) => Some(Ok(x.clone())),
+                Step::Error(e) => Some(Err(e.clone())),
+                Step::End => None,
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ScriptedIter<T, E> {
+    script: Script<T, E>,
+    index: usize,
+}
+
+impl<T, E> TryIterator for ScriptedIter<T, E>
+where
+    T: Clone,
+    E: Clone,
+{
+    type Item = T;
+    type Error = E;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let step = self.script.steps.get(self.index);
+        self.index += 1;
+        match step {
+            None => {
+                self.index = self.script.steps.len();
+                Ok(None)
+            }
+            Some(Step::Item(x)) => Ok(Some(x.clone())),
+            Some(Step::Error(e)) => Err(e.clone()),
+            Some(Step::End) => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.script.size_hints {
+            SizeHints::Accurate => {
+                let steps = self.script.steps.get(self.index..).unwrap_or(&[]);
+                let n = steps
+                    .iter()
+                    .take_while(|step| !matches!(step, Step::End))
+                    .filter(|step| matches!(step, Step::Item(_)))
+                    .count();
+                (n, Some(n))
+            }
+            SizeHints::Unknown => (0, None),
+            SizeHints::Misleading(lower, upper) => (lower, upper),
+        }
+    }
+}
+
+// runs `pipeline` over an iterator that follows `script` and checks that
+// calling `next` until it returns `Ok(None)` produces what `model` computes
+// from the events of the script. the other methods of the pipeline are then
+// checked against `next` with `check_try_iterator`, over a copy of the
+// script whose size hints are unknown rather than misleading. panics if
+// anything disagrees
+pub fn check_model<T, E, I, P, M>(script: &Script<T, E>, mut pipeline: P, model: M)
+where
+    T: Clone,
+    E: Clone,
+    P: FnMut(ScriptedIter<T, E>) -> I,
+    I: TryIterator,
+    I::Item: PartialEq + Debug,
+    I::Error: PartialEq + Debug,
+    M: FnOnce(Vec<Result<T, E>>) -> Vec<Result<I::Item, I::Error>>,
+{
+    let events = reference(&mut pipeline(script.iter()), I::next, "next");
+    assert_eq!(events, model(script.events()), "the pipeline disagrees with the model");
+
+    let script = match script.size_hints {
+        SizeHints::Misleading(..) => script.clone().with_size_hints(SizeHints::Unknown),
+        _ => script.clone(),
+    };
+    check_try_iterator(|| pipeline(script.iter()));
+}
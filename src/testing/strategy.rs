@@ -0,0 +1,66 @@
+use super::script::{Script, SizeHints, Step};
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use proptest_crate::{
+    collection::{vec, SizeRange},
+    option, prop_oneof,
+    strategy::{Just, Strategy},
+};
+
+// scripts of items and errors, with accurate size hints. they shrink towards
+// fewer steps and towards simpler items and errors
+pub fn script<T, E>(
+    item: impl Strategy<Value = T>,
+    error: impl Strategy<Value = E>,
+    len: impl Into<SizeRange>,
+) -> impl Strategy<Value = Script<T, E>>
+where
+    T: Clone + Debug,
+    E: Clone + Debug,
+{
+    steps(item, error, 0, len).prop_map(Script::new)
+}
+
+// scripts that can also end early and resume afterwards, and lie about their
+// size hints. iterators that are built on top of them don't have to be
+// correct, but they shouldn't misbehave in other ways
+pub fn wild_script<T, E>(
+    item: impl Strategy<Value = T>,
+    error: impl Strategy<Value = E>,
+    len: impl Into<SizeRange>,
+) -> impl Strategy<Value = Script<T, E>>
+where
+    T: Clone + Debug,
+    E: Clone + Debug,
+{
+    (steps(item, error, 1, len), size_hints())
+        .prop_map(|(steps, size_hints)| Script::new(steps).with_size_hints(size_hints))
+}
+
+pub fn size_hints() -> impl Strategy<Value = SizeHints> {
+    prop_oneof![
+        Just(SizeHints::Accurate),
+        Just(SizeHints::Unknown),
+        (0..16usize, option::of(0..16usize))
+            .prop_map(|(lower, upper)| SizeHints::Misleading(lower, upper)),
+    ]
+}
+
+fn steps<T, E>(
+    item: impl Strategy<Value = T>,
+    error: impl Strategy<Value = E>,
+    end_weight: u32,
+    len: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<Step<T, E>>>
+where
+    T: Clone + Debug,
+    E: Clone + Debug,
+{
+    let step = prop_oneof![
+        4 => item.prop_map(Step::Item),
+        1 => error.prop_map(Step::Error),
+        end_weight => Just(Step::End),
+    ];
+    vec(step, len)
+}
@@ -4,6 +4,7 @@ mod double_ended_try_iterator;
 mod exact_size_try_iterator;
 mod fused_try_iterator;
 mod into_try_iterator;
+mod try_extend;
 mod try_iterator;
 
 pub use self::try_iterator::*;
@@ -11,3 +12,4 @@ pub use double_ended_try_iterator::*;
 pub use exact_size_try_iterator::*;
 pub use fused_try_iterator::FusedTryIterator;
 pub use into_try_iterator::*;
+pub use try_extend::TryExtend;
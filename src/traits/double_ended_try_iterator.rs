@@ -4,7 +4,7 @@ pub trait DoubleEndedTryIterator: TryIterator {
     fn next_back(&mut self) -> Result<Option<Self::Item>, Self::Error>;
 
     fn nth_back(&mut self, n: usize) -> Result<Option<Self::Item>, Self::Error> {
-        self.try_nth(n).map(|x| x.ok())
+        self.try_nth_back(n).map(|x| x.ok())
     }
 
     fn try_nth_back(&mut self, mut n: usize) -> Result<Result<Self::Item, usize>, Self::Error> {
@@ -89,7 +89,7 @@ pub trait DoubleEndedTryIterator: TryIterator {
         R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        self.rev_mut().try_rfind(f)
+        self.rev_mut().try_find(f)
     }
 
     fn rposition<F>(&mut self, f: F) -> Result<Option<usize>, Self::Error>
@@ -144,12 +144,32 @@ pub trait DoubleEndedTryIterator: TryIterator {
         Try::from_ok(true_count)
     }
 
+    #[cfg(feature = "alloc")]
+    fn rsplit<F>(self, f: F) -> RSplit<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.try_rsplit(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_rsplit<F, R>(self, f: F) -> RSplit<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        RSplit::new(self, f)
+    }
+
     fn rev(self) -> Rev<Self>
     where Self: Sized {
         Rev::new(self)
     }
 
-    fn rev_mut<'a>(&'a mut self) -> RevMut<'a, Self>
+    fn rev_mut(&mut self) -> RevMut<'_, Self>
     where Self: Sized {
         RevMut::new(self)
     }
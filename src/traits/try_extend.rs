@@ -0,0 +1,23 @@
+use super::*;
+
+// the fallible counterpart of `Extend`. items that were added before the
+// iterator failed stay in the collection
+pub trait TryExtend<T, E> {
+    // returns the number of items that were added
+    fn try_extend<I>(&mut self, iter: I) -> Result<usize, E>
+    where I: IntoTryIterator<Item = T, Error = E>;
+}
+
+impl<C, T, E> TryExtend<T, E> for C
+where C: Extend<T>
+{
+    fn try_extend<I>(&mut self, iter: I) -> Result<usize, E>
+    where I: IntoTryIterator<Item = T, Error = E> {
+        let mut iter = iter.into_try_iter();
+        self.extend_reserve(iter.size_hint().0);
+        iter.try_fold(0, |n, x| {
+            self.extend_one(x);
+            Ok(n + 1)
+        })
+    }
+}
@@ -1,6 +1,20 @@
 use super::*;
 
-use core::iter::{FromIterator, Product, Sum};
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::BinaryHeap,
+    vec::{self, Vec},
+};
+#[cfg(feature = "alloc")]
+use core::cmp::Reverse;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::{
+    iter::{FromIterator, Product, Sum},
+    ops::ControlFlow,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 pub trait TryIterator {
     type Item;
@@ -26,6 +40,25 @@ pub trait TryIterator {
         Ok(Err(n))
     }
 
+    // reads the next `N` items. if the iterator runs out first, the items
+    // that were read are returned instead. an error drops them
+    fn next_chunk<const N: usize>(
+        &mut self,
+    ) -> Result<Result<[Self::Item; N], PartialArray<Self::Item, N>>, Self::Error>
+    where Self: Sized {
+        let mut buffer = ArrayBuffer::new();
+        if !buffer.is_full() {
+            self.any(|x| {
+                buffer.push(x);
+                buffer.is_full()
+            })?;
+        }
+        Ok(match buffer.take_array() {
+            Some(array) => Ok(array),
+            None => Err(PartialArray::new(buffer)),
+        })
+    }
+
     fn fold<Acc, F>(mut self, acc: Acc, mut f: F) -> Result<Acc, Self::Error>
     where
         Self: Sized,
@@ -65,6 +98,56 @@ pub trait TryIterator {
         self.next()?.try_map(|first| self.try_fold(first, f))
     }
 
+    // folds until the closure returns `FoldWhile::Done`. the result is `Done`
+    // if the fold stopped early and `Continue` if the iterator ran out
+    fn fold_while<B, F>(&mut self, init: B, f: F) -> Result<FoldWhile<B>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> FoldWhile<B>,
+    {
+        self.try_fold_while(init, FnWrapper::new(f))
+    }
+
+    fn try_fold_while<B, F, R>(&mut self, init: B, mut f: F) -> Result<FoldWhile<B>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Ok = FoldWhile<B>>,
+        R::Error: From<Self::Error>,
+    {
+        let state = self.try_fold_control(init, |acc, x| {
+            Ok::<_, R::Error>(match f(acc, x)? {
+                FoldWhile::Continue(acc) => ControlFlow::Continue(acc),
+                FoldWhile::Done(acc) => ControlFlow::Break(acc),
+            })
+        });
+        match state {
+            LoopState::Continue(acc) => Ok(FoldWhile::Continue(acc)),
+            LoopState::Break(acc) => Ok(FoldWhile::Done(acc)),
+            LoopState::IterError(e) => Err(e.into()),
+            LoopState::MapError(e) => Err(e),
+        }
+    }
+
+    // folds until the closure breaks, keeping errors of the iterator and of
+    // the closure apart. `LoopState::Continue` means the iterator ran out
+    fn try_fold_control<Acc, B, F, R>(
+        &mut self,
+        init: Acc,
+        mut f: F,
+    ) -> LoopState<Acc, B, Self::Error, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(Acc, Self::Item) -> R,
+        R: Try<Ok = ControlFlow<B, Acc>>,
+    {
+        self.try_fold(init, |acc, x| match f(acc, x).into_result() {
+            Ok(ControlFlow::Continue(acc)) => LoopState::Continue(acc),
+            Ok(ControlFlow::Break(x)) => LoopState::Break(x),
+            Err(e) => LoopState::MapError(e),
+        })
+    }
+
     fn for_each<F>(self, mut f: F) -> Result<(), Self::Error>
     where
         Self: Sized,
@@ -150,7 +233,7 @@ pub trait TryIterator {
         })
     }
 
-    fn any<F, R>(&mut self, f: F) -> Result<bool, Self::Error>
+    fn any<F>(&mut self, f: F) -> Result<bool, Self::Error>
     where
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
@@ -169,7 +252,7 @@ pub trait TryIterator {
         Try::from_ok(x?.is_some())
     }
 
-    fn all<F, R>(&mut self, f: F) -> Result<bool, Self::Error>
+    fn all<F>(&mut self, f: F) -> Result<bool, Self::Error>
     where
         Self: Sized,
         F: FnMut(Self::Item) -> bool,
@@ -184,7 +267,8 @@ pub trait TryIterator {
         R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        self.try_any(|x| Try::from_ok(!f(x)?))
+        let any: R = self.try_any(|x| Try::from_ok(!f(x)?));
+        Try::from_ok(!any?)
     }
 
     fn count(self) -> Result<usize, Self::Error>
@@ -293,535 +377,1737 @@ pub trait TryIterator {
             .map(|x| x.map(|(_, x)| x))
     }
 
-    fn partial_cmp_by<I, F>(self, other: I, f: F) -> Result<Option<Ordering>, Self::Error>
+    fn minmax(self) -> Result<MinMaxResult<Self::Item>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> Option<Ordering>,
-        Self::Error: From<I::Error>,
+        Self::Item: Ord,
     {
-        self.try_partial_cmp_by(other, FnWrapper::new(f))
+        self.minmax_by(Ord::cmp)
     }
 
-    fn try_partial_cmp_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    fn minmax_by<F>(self, f: F) -> Result<MinMaxResult<Self::Item>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> R,
-        R: Try<Ok = Option<Ordering>>,
-        R::Error: From<Self::Error> + From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        let mut other = other.into_try_iter();
-        self.try_for_each(|x| match MapResult::wrap(other.next())? {
-            None => LoopState::Break(Some(Ordering::Greater)),
-            Some(y) => match MapResult::wrap(f(x, y))? {
-                Some(Ordering::Equal) => LoopState::Continue(()),
-                non_eq => LoopState::Break(non_eq),
-            },
-        })
-        .try_map_continue(|()| {
-            Ok(Some(match MapResult::wrap(other.next())? {
-                None => Ordering::Equal,
-                Some(_) => Ordering::Less,
-            }))
-        })
-        .into_try()
+        self.try_minmax_by(FnWrapper::new(f))
     }
 
-    fn cmp_by<I, F>(self, other: I, f: F) -> Result<Ordering, Self::Error>
+    fn try_minmax_by<F, R>(self, f: F) -> Result<MinMaxResult<Self::Item>, R::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> Ordering,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error>,
     {
-        self.try_cmp_by(other, FnWrapper::new(f))
+        minmax::try_minmax_by(self, f)
     }
 
-    fn try_cmp_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    fn minmax_by_key<F, T>(self, f: F) -> Result<MinMaxResult<Self::Item>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> R,
-        R: Try<Ok = Ordering>,
-        R::Error: From<Self::Error> + From<I::Error>,
+        F: FnMut(&Self::Item) -> T,
+        T: Ord,
     {
-        let mut other = other.into_try_iter();
-        self.try_for_each(|x| match MapResult::wrap(other.next())? {
-            None => LoopState::Break(Ordering::Greater),
-            Some(y) => match MapResult::wrap(f(x, y))? {
-                Ordering::Equal => LoopState::Continue(()),
-                non_eq => LoopState::Break(non_eq),
-            },
-        })
-        .try_map_continue(|()| {
-            Ok(match MapResult::wrap(other.next())? {
-                None => Ordering::Equal,
-                Some(_) => Ordering::Less,
-            })
-        })
-        .into_try()
+        self.try_minmax_by_key(FnWrapper::new(f))
     }
 
-    fn eq_by<I, F>(self, other: I, f: F) -> Result<bool, Self::Error>
+    fn try_minmax_by_key<F, R, T>(self, mut f: F) -> Result<MinMaxResult<Self::Item>, R::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> bool,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        R::Error: From<Self::Error>,
+        T: Ord,
     {
-        self.try_eq_by(other, FnWrapper::new(f))
+        self.try_map(|x| Ok((f(&x)?, x)))
+            .minmax_by(|(x, _), (y, _)| x.cmp(y))
+            .map(|x| x.map(|(_, x)| x))
     }
 
-    fn try_eq_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    fn position_min(self) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        F: FnMut(Self::Item, I::Item) -> R,
-        R: Try<Ok = bool>,
-        R::Error: From<Self::Error> + From<I::Error>,
+        Self::Item: Ord,
     {
-        let mut other = other.into_try_iter();
-        self.try_for_each(|x| match MapResult::wrap(other.next())? {
-            None => LoopState::Break(false),
-            Some(y) => match MapResult::wrap(f(x, y))? {
-                true => LoopState::Continue(()),
-                false => LoopState::Break(false),
-            },
-        })
-        .try_map_continue(|()| Ok(MapResult::wrap(other.next())?.is_none()))
-        .into_try()
+        self.position_min_by(Ord::cmp)
     }
 
-    fn partial_cmp<I>(self, other: I) -> Result<Option<Ordering>, Self::Error>
+    fn position_min_by<F>(self, f: F) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        self.partial_cmp_by(other, |a, b| a.partial_cmp(&b))
+        self.try_position_min_by(FnWrapper::new(f))
     }
 
-    fn cmp<I>(self, other: I) -> Result<Ordering, Self::Error>
+    fn try_position_min_by<F, R>(self, mut f: F) -> Result<Option<usize>, R::Error>
     where
         Self: Sized,
-        I: IntoTryIterator<Item = Self::Item>,
-        Self::Item: Ord,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error>,
     {
-        self.cmp_by(other, |a, b| a.cmp(&b))
+        self.enumerate().try_min_by(|(_, x), (_, y)| f(x, y)).map(|x| x.map(|(i, _)| i))
     }
 
-    fn eq<I>(self, other: I) -> Result<bool, Self::Error>
+    fn position_min_by_key<F, T>(self, f: F) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> T,
+        T: Ord,
     {
-        self.eq_by(other, |a, b| a.eq(&b))
+        self.try_position_min_by_key(FnWrapper::new(f))
     }
 
-    fn lt<I>(self, other: I) -> Result<bool, Self::Error>
+    fn try_position_min_by_key<F, R, T>(self, mut f: F) -> Result<Option<usize>, R::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        R::Error: From<Self::Error>,
+        T: Ord,
     {
-        Ok(self.partial_cmp(other)? == Some(Ordering::Less))
+        self.enumerate().try_min_by_key(|(_, x)| f(x)).map(|x| x.map(|(i, _)| i))
     }
 
-    fn le<I>(self, other: I) -> Result<bool, Self::Error>
+    fn position_max(self) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self::Error: From<I::Error>,
+        Self::Item: Ord,
     {
-        Ok(match self.partial_cmp(other)? {
-            Some(Ordering::Less) | Some(Ordering::Equal) => true,
-            _ => false,
-        })
+        self.position_max_by(Ord::cmp)
     }
 
-    fn gt<I>(self, other: I) -> Result<bool, Self::Error>
+    fn position_max_by<F>(self, f: F) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        Ok(self.partial_cmp(other)? == Some(Ordering::Greater))
+        self.try_position_max_by(FnWrapper::new(f))
     }
 
-    fn ge<I>(self, other: I) -> Result<bool, Self::Error>
+    fn try_position_max_by<F, R>(self, mut f: F) -> Result<Option<usize>, R::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialOrd<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error>,
     {
-        Ok(match self.partial_cmp(other)? {
-            Some(Ordering::Greater) | Some(Ordering::Equal) => true,
-            _ => false,
-        })
+        self.enumerate().try_max_by(|(_, x), (_, y)| f(x, y)).map(|x| x.map(|(i, _)| i))
     }
 
-    fn ne<I>(self, other: I) -> Result<bool, Self::Error>
+    fn position_max_by_key<F, T>(self, f: F) -> Result<Option<usize>, Self::Error>
     where
         Self: Sized,
-        I: IntoTryIterator,
-        Self::Item: PartialEq<I::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> T,
+        T: Ord,
     {
-        Ok(!self.eq(other)?)
+        self.try_position_max_by_key(FnWrapper::new(f))
     }
 
-    fn is_sorted(self) -> Result<bool, Self::Error>
+    fn try_position_max_by_key<F, R, T>(self, mut f: F) -> Result<Option<usize>, R::Error>
     where
         Self: Sized,
-        Self::Item: PartialOrd,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = T>,
+        R::Error: From<Self::Error>,
+        T: Ord,
     {
-        self.is_sorted_by(|x, y| x <= y)
+        self.enumerate().try_max_by_key(|(_, x)| f(x)).map(|x| x.map(|(i, _)| i))
     }
 
-    fn is_sorted_by<F>(self, f: F) -> Result<bool, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn min_set(self) -> Result<Vec<Self::Item>, Self::Error>
     where
         Self: Sized,
-        F: FnMut(&Self::Item, &Self::Item) -> bool,
+        Self::Item: Ord,
     {
-        self.try_is_sorted_by(FnWrapper::new(f))
+        self.min_set_by(Ord::cmp)
     }
 
-    fn try_is_sorted_by<F, R>(self, mut f: F) -> R
+    #[cfg(feature = "alloc")]
+    fn min_set_by<F>(self, f: F) -> Result<Vec<Self::Item>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.try_min_set_by(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_min_set_by<F, R>(mut self, mut f: F) -> Result<Vec<Self::Item>, R::Error>
     where
         Self: Sized,
         F: FnMut(&Self::Item, &Self::Item) -> R,
-        R: Try<Ok = bool>,
+        R: Try<Ok = Ordering>,
         R::Error: From<Self::Error>,
     {
-        let x: LoopState<_, _, R::Error, _> = try {
-            self.map_err(R::Error::from).try_fold1(|x, y| match f(&x, &y)? {
-                true => LoopState::Continue(y),
-                false => LoopState::Break(false),
-            })?
-        };
-        x.map_continue(|_| true).into_try()
+        self.try_fold(Vec::new(), |mut set, x| {
+            match set.first().try_map(|y| f(&x, y))? {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => set.push(x),
+                Some(Ordering::Less) | None => {
+                    set.clear();
+                    set.push(x);
+                }
+            }
+            Ok(set)
+        })
     }
 
-    fn is_sorted_by_key<F, K>(self, f: F) -> Result<bool, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn min_set_by_key<F, K>(self, f: F) -> Result<Vec<Self::Item>, Self::Error>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> K,
-        K: PartialOrd,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
     {
-        self.try_is_sorted_by_key(FnWrapper::new(f))
+        self.try_min_set_by_key(FnWrapper::new(f))
     }
 
-    fn try_is_sorted_by_key<F, R, K>(self, f: F) -> Result<bool, R::Error>
+    #[cfg(feature = "alloc")]
+    fn try_min_set_by_key<F, R, K>(mut self, mut f: F) -> Result<Vec<Self::Item>, R::Error>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
+        F: FnMut(&Self::Item) -> R,
         R: Try<Ok = K>,
         R::Error: From<Self::Error>,
-        K: PartialOrd,
-    {
-        self.try_map(f).is_sorted()
+        K: Ord,
+    {
+        let mut min = None;
+        self.try_fold(Vec::new(), |mut set, x| {
+            let key = f(&x)?;
+            match min.as_ref().map(|min| key.cmp(min)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => set.push(x),
+                Some(Ordering::Less) | None => {
+                    min = Some(key);
+                    set.clear();
+                    set.push(x);
+                }
+            }
+            Ok(set)
+        })
     }
 
-    fn collect<B>(self) -> Result<B, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn max_set(self) -> Result<Vec<Self::Item>, Self::Error>
     where
         Self: Sized,
-        B: FromIterator<Self::Item>,
+        Self::Item: Ord,
     {
-        self.into_results().collect()
+        self.max_set_by(Ord::cmp)
     }
 
-    fn sum<B>(self) -> Result<B, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn max_set_by<F>(self, f: F) -> Result<Vec<Self::Item>, Self::Error>
     where
         Self: Sized,
-        B: Sum<Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        self.into_results().sum()
+        self.try_max_set_by(FnWrapper::new(f))
     }
 
-    fn product<B>(self) -> Result<B, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn try_max_set_by<F, R>(self, mut f: F) -> Result<Vec<Self::Item>, R::Error>
     where
         Self: Sized,
-        B: Product<Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error>,
     {
-        self.into_results().product()
+        self.try_min_set_by(|x, y| Ok::<_, R::Error>(f(x, y)?.reverse()))
     }
 
-    fn partition<B, F, R>(self, f: F) -> Result<(B, B), Self::Error>
+    #[cfg(feature = "alloc")]
+    fn max_set_by_key<F, K>(self, f: F) -> Result<Vec<Self::Item>, Self::Error>
     where
         Self: Sized,
-        B: Default + Extend<Self::Item>,
-        F: FnMut(&Self::Item) -> bool,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
     {
-        self.try_partition(FnWrapper::new(f))
+        self.try_max_set_by_key(FnWrapper::new(f))
     }
 
-    fn try_partition<B, F, R>(mut self, mut f: F) -> Result<(B, B), R::Error>
+    #[cfg(feature = "alloc")]
+    fn try_max_set_by_key<F, R, K>(self, mut f: F) -> Result<Vec<Self::Item>, R::Error>
     where
         Self: Sized,
-        B: Default + Extend<Self::Item>,
         F: FnMut(&Self::Item) -> R,
-        R: Try<Ok = bool>,
+        R: Try<Ok = K>,
         R::Error: From<Self::Error>,
+        K: Ord,
     {
-        self.try_fold((B::default(), B::default()), |(mut a, mut b), i| {
-            if f(&i)? {
-                a.extend(Some(i));
-            } else {
-                b.extend(Some(i));
-            }
-            Ok((a, b))
-        })
+        self.try_min_set_by_key(|x| Ok::<_, R::Error>(Reverse(f(x)?)))
     }
 
-    fn is_partitioned<F>(self, f: F) -> Result<bool, Self::Error>
+    #[cfg(feature = "alloc")]
+    fn k_smallest(self, k: usize) -> Result<vec::IntoIter<Self::Item>, Self::Error>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> bool,
+        Self::Item: Ord,
     {
-        self.try_is_partitioned(FnWrapper::new(f))
+        // the heap holds the `k` smallest items seen so far, with the largest
+        // of those on top
+        let mut heap = BinaryHeap::with_capacity(k);
+        self.for_each(|x| {
+            if heap.len() < k {
+                heap.push(x);
+            } else if let Some(mut top) = heap.peek_mut() {
+                if x < *top {
+                    *top = x;
+                }
+            }
+        })?;
+        Ok(heap.into_sorted_vec().into_iter())
     }
 
-    fn try_is_partitioned<F, R>(mut self, mut f: F) -> R
+    #[cfg(feature = "alloc")]
+    fn k_largest(self, k: usize) -> Result<vec::IntoIter<Self::Item>, Self::Error>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
-        R: Try<Ok = bool>,
-        R::Error: From<Self::Error>,
+        Self::Item: Ord,
     {
-        try { self.try_all(&mut f)? || !self.try_any(f)? }
+        let smallest = self.map(Reverse).k_smallest(k)?;
+        Ok(smallest.map(|Reverse(x)| x).collect::<Vec<_>>().into_iter())
     }
 
-    fn unzip<A, B, FromA, FromB>(self) -> Result<(FromA, FromB), Self::Error>
+    #[cfg(feature = "alloc")]
+    fn sorted(self) -> Result<vec::IntoIter<Self::Item>, Self::Error>
     where
-        Self: Sized + TryIterator<Item = (A, B)>,
-        FromA: Default + Extend<A>,
-        FromB: Default + Extend<B>,
+        Self: Sized,
+        Self::Item: Ord,
     {
-        self.fold((Default::default(), Default::default()), |(mut xs, mut ys), (x, y)| {
-            xs.extend(Some(x));
-            ys.extend(Some(y));
-            (xs, ys)
-        })
+        self.sorted_by(Ord::cmp)
     }
 
-    fn by_ref(&mut self) -> &mut Self
-    where Self: Sized {
-        self
+    #[cfg(feature = "alloc")]
+    fn sorted_by<F>(self, f: F) -> Result<vec::IntoIter<Self::Item>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.try_sorted_by(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by<F, R>(self, mut f: F) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error>,
+    {
+        // the sort can't be interrupted, so after the first error every pair
+        // compares as equal and the error is returned once the sort is done
+        let mut v: Vec<_> = self.collect()?;
+        let mut error = None;
+        v.sort_by(|x, y| match error {
+            Some(_) => Ordering::Equal,
+            None => f(x, y).into_result().unwrap_or_else(|e| {
+                error = Some(e);
+                Ordering::Equal
+            }),
+        });
+        match error {
+            None => Ok(v.into_iter()),
+            Some(e) => Err(e),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sorted_by_key<F, K>(self, f: F) -> Result<vec::IntoIter<Self::Item>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        self.try_sorted_by_key(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by_key<F, R, K>(self, mut f: F) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: Ord,
+    {
+        self.try_sorted_by(|x, y| Ok::<_, R::Error>(f(x)?.cmp(&f(y)?)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn sorted_by_cached_key<F, K>(self, f: F) -> Result<vec::IntoIter<Self::Item>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Ord,
+    {
+        self.try_sorted_by_cached_key(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by_cached_key<F, R, K>(
+        self,
+        mut f: F,
+    ) -> Result<vec::IntoIter<Self::Item>, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: Ord,
+    {
+        // every key is computed exactly once before any comparisons are made,
+        // so an error means that the order of the items doesn't matter
+        let mut v: Vec<_> = self.collect()?;
+        let mut error = None;
+        v.sort_by_cached_key(|x| match error {
+            Some(_) => None,
+            None => f(x).into_result().map_err(|e| error = Some(e)).ok(),
+        });
+        match error {
+            None => Ok(v.into_iter()),
+            Some(e) => Err(e),
+        }
+    }
+
+    fn partial_cmp_by<I, F>(self, other: I, f: F) -> Result<Option<Ordering>, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> Option<Ordering>,
+        Self::Error: From<I::Error>,
+    {
+        self.try_partial_cmp_by(other, FnWrapper::new(f))
+    }
+
+    fn try_partial_cmp_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> R,
+        R: Try<Ok = Option<Ordering>>,
+        R::Error: From<Self::Error> + From<I::Error>,
+    {
+        let mut other = other.into_try_iter();
+        self.try_for_each(|x| match MapResult::wrap(other.next())? {
+            None => LoopState::Break(Some(Ordering::Greater)),
+            Some(y) => match MapResult::wrap(f(x, y))? {
+                Some(Ordering::Equal) => LoopState::Continue(()),
+                non_eq => LoopState::Break(non_eq),
+            },
+        })
+        .try_map_continue(|()| {
+            Ok(Some(match MapResult::wrap(other.next())? {
+                None => Ordering::Equal,
+                Some(_) => Ordering::Less,
+            }))
+        })
+        .into_try()
+    }
+
+    fn cmp_by<I, F>(self, other: I, f: F) -> Result<Ordering, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> Ordering,
+        Self::Error: From<I::Error>,
+    {
+        self.try_cmp_by(other, FnWrapper::new(f))
+    }
+
+    fn try_cmp_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error> + From<I::Error>,
+    {
+        let mut other = other.into_try_iter();
+        self.try_for_each(|x| match MapResult::wrap(other.next())? {
+            None => LoopState::Break(Ordering::Greater),
+            Some(y) => match MapResult::wrap(f(x, y))? {
+                Ordering::Equal => LoopState::Continue(()),
+                non_eq => LoopState::Break(non_eq),
+            },
+        })
+        .try_map_continue(|()| {
+            Ok(match MapResult::wrap(other.next())? {
+                None => Ordering::Equal,
+                Some(_) => Ordering::Less,
+            })
+        })
+        .into_try()
+    }
+
+    fn eq_by<I, F>(self, other: I, f: F) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> bool,
+        Self::Error: From<I::Error>,
+    {
+        self.try_eq_by(other, FnWrapper::new(f))
+    }
+
+    fn try_eq_by<I, F, R>(mut self, other: I, mut f: F) -> R
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        F: FnMut(Self::Item, I::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error> + From<I::Error>,
+    {
+        let mut other = other.into_try_iter();
+        self.try_for_each(|x| match MapResult::wrap(other.next())? {
+            None => LoopState::Break(false),
+            Some(y) => match MapResult::wrap(f(x, y))? {
+                true => LoopState::Continue(()),
+                false => LoopState::Break(false),
+            },
+        })
+        .try_map_continue(|()| Ok(MapResult::wrap(other.next())?.is_none()))
+        .into_try()
+    }
+
+    fn partial_cmp<I>(self, other: I) -> Result<Option<Ordering>, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialOrd<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        self.partial_cmp_by(other, |a, b| a.partial_cmp(&b))
+    }
+
+    fn cmp<I>(self, other: I) -> Result<Ordering, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator<Item = Self::Item>,
+        Self::Item: Ord,
+        Self::Error: From<I::Error>,
+    {
+        self.cmp_by(other, |a, b| a.cmp(&b))
+    }
+
+    fn eq<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialEq<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        self.eq_by(other, |a, b| a.eq(&b))
+    }
+
+    fn lt<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialOrd<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Ok(self.partial_cmp(other)? == Some(Ordering::Less))
+    }
+
+    fn le<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialOrd<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Ok(matches!(self.partial_cmp(other)?, Some(Ordering::Less) | Some(Ordering::Equal)))
+    }
+
+    fn gt<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialOrd<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Ok(self.partial_cmp(other)? == Some(Ordering::Greater))
+    }
+
+    fn ge<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialOrd<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Ok(matches!(self.partial_cmp(other)?, Some(Ordering::Greater) | Some(Ordering::Equal)))
+    }
+
+    fn ne<I>(self, other: I) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        I: IntoTryIterator,
+        Self::Item: PartialEq<I::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Ok(!self.eq(other)?)
+    }
+
+    fn is_sorted(self) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        Self::Item: PartialOrd,
+    {
+        self.is_sorted_by(|x, y| x <= y)
+    }
+
+    fn is_sorted_by<F>(self, f: F) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        self.try_is_sorted_by(FnWrapper::new(f))
+    }
+
+    fn try_is_sorted_by<F, R>(self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        let x: LoopState<_, _, R::Error, _> = try {
+            self.map_err(R::Error::from).try_fold1(|x, y| match f(&x, &y)? {
+                true => LoopState::Continue(y),
+                false => LoopState::Break(false),
+            })?
+        };
+        x.map_continue(|_| true).into_try()
+    }
+
+    fn is_sorted_by_key<F, K>(self, f: F) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> K,
+        K: PartialOrd,
+    {
+        self.try_is_sorted_by_key(FnWrapper::new(f))
+    }
+
+    fn try_is_sorted_by_key<F, R, K>(self, f: F) -> Result<bool, R::Error>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: PartialOrd,
+    {
+        self.try_map(f).is_sorted()
+    }
+
+    fn collect<B>(self) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        B: FromIterator<Self::Item>,
+    {
+        self.into_results().collect()
+    }
+
+    fn collect_array<const N: usize>(
+        mut self,
+    ) -> Result<[Self::Item; N], CollectArrayError<Self::Item, Self::Error, N>>
+    where Self: Sized {
+        let array = match self.next_chunk()? {
+            Ok(array) => array,
+            Err(partial) => return Err(CollectArrayError::TooFew(partial)),
+        };
+        match self.next()? {
+            None => Ok(array),
+            Some(x) => Err(CollectArrayError::TooMany(array, x)),
+        }
+    }
+
+    // reads at most two items, so the source isn't drained when there are
+    // too many
+    fn exactly_one(self) -> Result<Self::Item, ExactlyOneError<Self::Item, Self, Self::Error>>
+    where Self: Sized {
+        match self.at_most_one() {
+            Ok(Some(x)) => Ok(x),
+            Ok(None) => Err(ExactlyOneError::Empty),
+            Err(AtMostOneError::TooMany(too_many)) => Err(ExactlyOneError::TooMany(too_many)),
+            Err(AtMostOneError::IterError(e)) => Err(ExactlyOneError::IterError(e)),
+        }
+    }
+
+    // if reading the second item fails, the first one is dropped along with
+    // the iterator and only the error is returned
+    fn at_most_one(
+        mut self,
+    ) -> Result<Option<Self::Item>, AtMostOneError<Self::Item, Self, Self::Error>>
+    where Self: Sized {
+        let first = match self.next()? {
+            None => return Ok(None),
+            Some(x) => x,
+        };
+        match self.next()? {
+            None => Ok(Some(first)),
+            Some(second) => Err(AtMostOneError::TooMany(TooMany { first, second, rest: self })),
+        }
+    }
+
+    // the item if there is exactly one, without saying why there isn't.
+    // reads at most two items, like `exactly_one`
+    fn single(mut self) -> Result<Option<Self::Item>, Self::Error>
+    where Self: Sized {
+        let first = match self.next()? {
+            None => return Ok(None),
+            Some(x) => x,
+        };
+        Ok(match self.next()? {
+            None => Some(first),
+            Some(_) => None,
+        })
+    }
+
+    fn try_extend_into<C>(self, collection: &mut C) -> Result<usize, Self::Error>
+    where
+        Self: Sized,
+        C: TryExtend<Self::Item, Self::Error>,
+    {
+        collection.try_extend(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn collect_into(self, vec: &mut Vec<Self::Item>) -> Result<usize, Self::Error>
+    where Self: Sized {
+        vec.try_extend(self)
+    }
+
+    fn collect_bounded<B>(mut self) -> Result<B, CapacityError<Self::Item, Self::Error>>
+    where
+        Self: Sized,
+        B: BoundedCollection<Self::Item> + Default,
+    {
+        let mut collection = B::default();
+        self.try_extend_bounded(&mut collection)?;
+        Ok(collection)
+    }
+
+    // items that were pushed before the collection filled up or the iterator
+    // failed stay in the collection
+    fn try_extend_bounded<B>(
+        &mut self,
+        collection: &mut B,
+    ) -> Result<(), CapacityError<Self::Item, Self::Error>>
+    where
+        Self: Sized,
+        B: BoundedCollection<Self::Item>,
+    {
+        self.try_for_each(|x| collection.try_push(x).map_err(CapacityError::Full))
+    }
+
+    // collects key-value pairs into a map, failing on the first key that
+    // occurs twice. the error holds both values for that key, the rest of the
+    // map is dropped
+    fn collect_map_unique<M, K, V>(mut self) -> Result<M, CollectMapError<K, V, Self::Error>>
+    where
+        Self: Sized + TryIterator<Item = (K, V)>,
+        M: GroupMap<K, V>,
+    {
+        self.try_fold(M::default(), |mut map, (key, value)| {
+            if let Some(first) = map.remove(&key) {
+                return Err(CollectMapError::DuplicateKey(DuplicateKey {
+                    key,
+                    first,
+                    second: value,
+                }));
+            }
+            map.insert(key, value);
+            Ok(map)
+        })
+    }
+
+    fn sum<B>(self) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        B: Sum<Self::Item>,
+    {
+        self.into_results().sum()
+    }
+
+    fn product<B>(self) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        B: Product<Self::Item>,
+    {
+        self.into_results().product()
+    }
+
+    // runs a reducer, or a tuple of reducers, over the iterator
+    fn reduce_with<R>(self, mut reducer: R) -> Result<R::Output, Self::Error>
+    where
+        Self: Sized,
+        R: Reducer<Self::Item>,
+    {
+        let state = reducer.init();
+        let state = self.fold(state, |state, x| reducer.step(state, x))?;
+        Ok(reducer.finish(state))
+    }
+
+    fn checked_sum<S>(self) -> Result<S, ArithmeticError<Self::Error>>
+    where
+        Self: Sized,
+        S: TrySum<Self::Item>,
+    {
+        S::try_sum(self)
+    }
+
+    fn checked_product<P>(self) -> Result<P, ArithmeticError<Self::Error>>
+    where
+        Self: Sized,
+        P: TryProduct<Self::Item>,
+    {
+        P::try_product(self)
+    }
+
+    fn saturating_sum<S>(self) -> Result<S, Self::Error>
+    where
+        Self: Sized,
+        S: SaturatingSum<Self::Item>,
+    {
+        S::saturating_sum(self)
+    }
+
+    fn partition<B, F>(self, f: F) -> Result<(B, B), Self::Error>
+    where
+        Self: Sized,
+        B: Default + Extend<Self::Item>,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.try_partition(FnWrapper::new(f))
+    }
+
+    fn try_partition<B, F, R>(mut self, mut f: F) -> Result<(B, B), R::Error>
+    where
+        Self: Sized,
+        B: Default + Extend<Self::Item>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        self.try_fold((B::default(), B::default()), |(mut a, mut b), i| {
+            if f(&i)? {
+                a.extend_one(i);
+            } else {
+                b.extend_one(i);
+            }
+            Ok((a, b))
+        })
+    }
+
+    fn is_partitioned<F>(self, f: F) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> bool,
+    {
+        self.try_is_partitioned(FnWrapper::new(f))
+    }
+
+    fn try_is_partitioned<F, R>(mut self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        try { self.try_all(&mut f)? || !self.try_any(f)? }
+    }
+
+    fn unzip<A, B, FromA, FromB>(self) -> Result<(FromA, FromB), Self::Error>
+    where
+        Self: Sized + TryIterator<Item = (A, B)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut xs = FromA::default();
+        let mut ys = FromB::default();
+        let (lower, _) = self.size_hint();
+        xs.extend_reserve(lower);
+        ys.extend_reserve(lower);
+        self.fold((xs, ys), |(mut xs, mut ys), (x, y)| {
+            xs.extend_one(x);
+            ys.extend_one(y);
+            (xs, ys)
+        })
+    }
+
+    fn counts<M>(self) -> Result<M, Self::Error>
+    where
+        Self: Sized,
+        M: GroupMap<Self::Item, usize>,
+    {
+        self.counts_by(|x| x)
+    }
+
+    fn counts_by<M, F, K>(self, f: F) -> Result<M, Self::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, usize>,
+        F: FnMut(Self::Item) -> K,
+    {
+        self.try_counts_by(FnWrapper::new(f))
+    }
+
+    fn try_counts_by<M, F, R, K>(mut self, mut f: F) -> Result<M, R::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, usize>,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+    {
+        self.try_fold(M::default(), |mut map, x| {
+            let key = f(x)?;
+            match map.get_mut(&key) {
+                Some(count) => *count += 1,
+                None => {
+                    map.insert(key, 1);
+                }
+            }
+            Ok(map)
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn into_group_map<M, K, V>(mut self) -> Result<M, Self::Error>
+    where
+        Self: Sized + TryIterator<Item = (K, V)>,
+        M: GroupMap<K, Vec<V>>,
+    {
+        self.try_fold(M::default(), |mut map, (key, value)| {
+            match map.get_mut(&key) {
+                Some(values) => values.push(value),
+                None => {
+                    map.insert(key, alloc::vec![value]);
+                }
+            }
+            Ok(map)
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn into_group_map_by<M, F, K>(self, f: F) -> Result<M, Self::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, Vec<Self::Item>>,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.try_into_group_map_by(FnWrapper::new(f))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_into_group_map_by<M, F, R, K>(self, f: F) -> Result<M, R::Error>
+    where
+        Self: Sized,
+        M: GroupMap<K, Vec<Self::Item>>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+    {
+        KeyBy::new(self, f).into_group_map()
+    }
+
+    fn into_grouping_map<K, V>(self) -> GroupingMap<Self>
+    where Self: Sized + TryIterator<Item = (K, V)> {
+        GroupingMap::new(self)
+    }
+
+    fn into_grouping_map_by<F, K>(self, f: F) -> GroupingMapBy<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.try_into_grouping_map_by(FnWrapper::new(f))
+    }
+
+    fn try_into_grouping_map_by<F, R, K>(self, f: F) -> GroupingMapBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+    {
+        GroupingMap::by(self, f)
+    }
+
+    fn unique<S>(self) -> Unique<Self, S>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        S: KeySet<Self::Item>,
+    {
+        UniqueBy::new(self, FnWrapper::new(Clone::clone))
+    }
+
+    fn unique_by<S, F, K>(self, f: F) -> UniqueBy<Self, FnWrapper<F, Self::Error>, S>
+    where
+        Self: Sized,
+        S: KeySet<K>,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.try_unique_by(FnWrapper::new(f))
+    }
+
+    fn try_unique_by<S, F, R, K>(self, f: F) -> UniqueBy<Self, F, S>
+    where
+        Self: Sized,
+        S: KeySet<K>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+    {
+        UniqueBy::new(self, f)
+    }
+
+    fn duplicates<M>(self) -> Duplicates<Self, M>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        M: GroupMap<Self::Item, bool>,
+    {
+        DuplicatesBy::new(self, FnWrapper::new(Clone::clone))
+    }
+
+    fn duplicates_by<M, F, K>(self, f: F) -> DuplicatesBy<Self, FnWrapper<F, Self::Error>, M>
+    where
+        Self: Sized,
+        M: GroupMap<K, bool>,
+        F: FnMut(&Self::Item) -> K,
+    {
+        self.try_duplicates_by(FnWrapper::new(f))
+    }
+
+    fn try_duplicates_by<M, F, R, K>(self, f: F) -> DuplicatesBy<Self, F, M>
+    where
+        Self: Sized,
+        M: GroupMap<K, bool>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+    {
+        DuplicatesBy::new(self, f)
+    }
+
+    fn all_unique<S>(mut self) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        S: KeySet<Self::Item>,
+    {
+        let mut seen = S::default();
+        self.all(|x| seen.insert(x))
+    }
+
+    fn all_equal(mut self) -> Result<bool, Self::Error>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        match self.next()? {
+            None => Ok(true),
+            Some(first) => self.all(|x| x == first),
+        }
+    }
+
+    fn by_ref(&mut self) -> &mut Self
+    where Self: Sized {
+        self
+    }
+
+    fn filter<F>(self, f: F) -> Filter<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.try_filter(FnWrapper::new(f))
+    }
+
+    fn try_filter<F, R>(self, f: F) -> Filter<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        Filter::new(self, f)
+    }
+
+    fn inspect<F>(self, f: F) -> Inspect<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        self.try_inspect(FnWrapper::new(f))
+    }
+
+    fn try_inspect<F, R>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = ()>,
+        R::Error: From<Self::Error>,
+    {
+        Inspect::new(self, f)
+    }
+
+    fn map<F, T>(self, f: F) -> Map<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+    {
+        self.try_map(FnWrapper::new(f))
+    }
+
+    fn try_map<F, R>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try,
+        R::Error: From<Self::Error>,
+    {
+        Map::new(self, f)
+    }
+
+    fn filter_map<F, R, T>(self, f: F) -> FilterMap<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<T>,
+    {
+        self.try_filter_map(FnWrapper::new(f))
+    }
+
+    fn try_filter_map<F, R, T>(self, f: F) -> FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = Option<T>>,
+        R::Error: From<Self::Error>,
+    {
+        FilterMap::new(self, f)
+    }
+
+    fn flat_map<F, R, U>(
+        self,
+        f: F,
+    ) -> Flatten<Map<Self, FnWrapper<F, Self::Error>>, U::IntoTryIter>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> U,
+        U: IntoTryIterator,
+        Self::Error: From<U::Error>,
+    {
+        self.try_flat_map(FnWrapper::new(f))
+    }
+
+    fn try_flat_map<F, R, U>(self, f: F) -> Flatten<Map<Self, F>, U::IntoTryIter>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = U>,
+        U: IntoTryIterator,
+        R::Error: From<Self::Error> + From<U::Error>,
+    {
+        self.try_map(f).flatten()
+    }
+
+    fn flatten<I>(self) -> Flatten<Self, I>
+    where
+        Self: Sized,
+        Self::Item: IntoTryIterator<Item = I::Item, Error = I::Error, IntoTryIter = I>,
+        Self::Error: From<I::Error>,
+        I: TryIterator,
+    {
+        Flatten::new(self)
+    }
+
+    fn zip<I>(self, other: I) -> Zip<Self, I>
+    where
+        Self: Sized,
+        I: TryIterator,
+        Self::Error: From<I::Error>,
+    {
+        Zip::new(self, other)
+    }
+
+    fn cartesian_product<I>(self, other: I) -> CartesianProduct<Self, I::IntoTryIter>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        I: IntoTryIterator,
+        I::IntoTryIter: Clone,
+        Self::Error: From<I::Error>,
+    {
+        CartesianProduct::new(self, other.into_try_iter())
+    }
+
+    fn merge_join_by<I, F>(self, other: I, f: F) -> MergeJoinBy<Self, I, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        I: TryIterator,
+        F: FnMut(&Self::Item, &I::Item) -> Ordering,
+        Self::Error: From<I::Error>,
+    {
+        self.try_merge_join_by(other, FnWrapper::new(f))
+    }
+
+    fn try_merge_join_by<I, F, R>(self, other: I, f: F) -> MergeJoinBy<Self, I, F>
+    where
+        Self: Sized,
+        I: TryIterator,
+        F: FnMut(&Self::Item, &I::Item) -> R,
+        R: Try<Ok = Ordering>,
+        R::Error: From<Self::Error> + From<I::Error>,
+    {
+        MergeJoinBy::new(self, other, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn hash_join<I, K, FA, FB>(
+        self,
+        other: I,
+        left_key: FA,
+        right_key: FB,
+    ) -> HashJoin<Self, I, FA, FB, HashMap<K, usize>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        I: TryIterator,
+        I::Item: Clone,
+        Self::Error: From<I::Error>,
+        K: Hash + Eq,
+        FA: FnMut(&Self::Item) -> K,
+        FB: FnMut(&I::Item) -> K,
+    {
+        self.hash_join_in(other, left_key, right_key)
+    }
+
+    // like `hash_join`, with the keys in a map of type `M`, such as a
+    // `BTreeMap` when `std` isn't available
+    #[cfg(feature = "alloc")]
+    fn hash_join_in<I, K, FA, FB, M>(
+        self,
+        other: I,
+        left_key: FA,
+        right_key: FB,
+    ) -> HashJoin<Self, I, FA, FB, M>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        I: TryIterator,
+        I::Item: Clone,
+        Self::Error: From<I::Error>,
+        FA: FnMut(&Self::Item) -> K,
+        FB: FnMut(&I::Item) -> K,
+        M: GroupMap<K, usize>,
+    {
+        HashJoin::new(self, other, left_key, right_key)
+    }
+
+    fn chain<I>(self, other: I) -> Chain<Self, I>
+    where
+        Self: Sized,
+        I: TryIterator<Item = Self::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Chain::new(self, other)
+    }
+
+    fn interleave<I>(self, other: I) -> Interleave<Self, I>
+    where
+        Self: Sized,
+        I: TryIterator<Item = Self::Item>,
+        Self::Error: From<I::Error>,
+    {
+        Interleave::new(self, other)
+    }
+
+    fn interleave_shortest<I>(self, other: I) -> InterleaveShortest<Self, I>
+    where
+        Self: Sized,
+        I: TryIterator<Item = Self::Item>,
+        Self::Error: From<I::Error>,
+    {
+        InterleaveShortest::new(self, other)
+    }
+
+    fn intersperse(self, sep: Self::Item) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Intersperse::new(self, sep)
+    }
+
+    fn intersperse_with<F>(self, f: F) -> IntersperseWith<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Item,
+    {
+        self.try_intersperse_with(FnWrapper::new(f))
     }
 
-    fn filter<F>(self, f: F) -> Filter<Self, FnWrapper<F, Self::Error>>
+    fn try_intersperse_with<F, R>(self, f: F) -> IntersperseWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> R,
+        R: Try<Ok = Self::Item>,
+        R::Error: From<Self::Error>,
+    {
+        IntersperseWith::new(self, f)
+    }
+
+    fn take(self, n: usize) -> Take<Self>
+    where Self: Sized {
+        Take::new(self, n)
+    }
+
+    // unlike `take`, items past `n` are an error rather than left unread
+    fn expect_len(self, n: usize) -> ExpectLen<Self>
+    where Self: Sized {
+        ExpectLen::new(self, n)
+    }
+
+    fn at_least(self, n: usize) -> AtLeast<Self>
+    where Self: Sized {
+        AtLeast::new(self, n)
+    }
+
+    fn at_most(self, n: usize) -> AtMost<Self>
+    where Self: Sized {
+        AtMost::new(self, n)
+    }
+
+    fn take_while<F>(self, f: F) -> TakeWhile<Self, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> bool,
     {
-        self.try_filter(FnWrapper::new(f))
+        self.try_take_while(FnWrapper::new(f))
     }
 
-    fn try_filter<F, R>(self, f: F) -> Filter<Self, F>
+    fn try_take_while<F, R>(self, f: F) -> TakeWhile<Self, F>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> R,
         R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        Filter::new(self, f)
+        TakeWhile::new(self, f)
     }
 
-    fn inspect<F>(self, f: F) -> Inspect<Self, FnWrapper<F, Self::Error>>
+    fn map_while<F, T>(self, f: F) -> MapWhile<Self, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
-        F: FnMut(&Self::Item),
+        F: FnMut(Self::Item) -> Option<T>,
     {
-        self.try_inspect(FnWrapper::new(f))
+        self.try_map_while(FnWrapper::new(f))
     }
 
-    fn try_inspect<F, R>(self, f: F) -> Inspect<Self, F>
+    fn try_map_while<F, R, T>(self, f: F) -> MapWhile<Self, F>
     where
         Self: Sized,
-        F: FnMut(&Self::Item) -> R,
-        R: Try<Ok = ()>,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Ok = Option<T>>,
         R::Error: From<Self::Error>,
     {
-        Inspect::new(self, f)
+        MapWhile::new(self, f)
     }
 
-    fn map<F, T>(self, f: F) -> Map<Self, FnWrapper<F, Self::Error>>
+    fn skip(self, n: usize) -> Skip<Self>
+    where Self: Sized {
+        Skip::new(self, n)
+    }
+
+    fn skip_while<F>(self, f: F) -> SkipWhile<Self, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> T,
+        F: FnMut(&Self::Item) -> bool,
     {
-        self.try_map(FnWrapper::new(f))
+        self.try_skip_while(FnWrapper::new(f))
     }
 
-    fn try_map<F, R>(self, f: F) -> Map<Self, F>
+    fn try_skip_while<F, R>(self, f: F) -> SkipWhile<Self, F>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
-        R: Try,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        Map::new(self, f)
+        SkipWhile::new(self, f)
     }
 
-    fn filter_map<F, R, T>(self, f: F) -> FilterMap<Self, FnWrapper<F, Self::Error>>
+    fn scan<St, F, R, T>(self, state: St, f: F) -> Scan<Self, St, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> Option<T>,
+        F: FnMut(&mut St, Self::Item) -> Option<T>,
     {
-        self.try_filter_map(FnWrapper::new(f))
+        Scan::new(self, state, FnWrapper::new(f))
     }
 
-    fn try_filter_map<F, R, T>(self, f: F) -> FilterMap<Self, F>
+    fn try_scan<St, F, R, T>(self, state: St, f: F) -> Scan<Self, St, F>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
+        F: FnMut(&mut St, Self::Item) -> R,
         R: Try<Ok = Option<T>>,
         R::Error: From<Self::Error>,
     {
-        FilterMap::new(self, f)
+        Scan::new(self, state, f)
     }
 
-    fn flat_map<F, R, U>(
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.dedup_by(PartialEq::eq as fn(&_, &_) -> _)
+    }
+
+    fn dedup_by<F>(self, f: F) -> DedupBy<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        self.try_dedup_by(FnWrapper::new(f))
+    }
+
+    fn try_dedup_by<F, R>(self, f: F) -> DedupBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        DedupBy::new(self, f)
+    }
+
+    fn dedup_by_key<F, K>(self, f: F) -> DedupByKey<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        self.try_dedup_by_key(FnWrapper::new(f))
+    }
+
+    fn try_dedup_by_key<F, R, K>(self, f: F) -> DedupByKey<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, f)
+    }
+
+    fn dedup_with_count(self) -> DedupWithCount<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupWithCount::new(self)
+    }
+
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>,
+    {
+        self.try_coalesce(FnWrapper::new(f))
+    }
+
+    fn try_coalesce<F, R>(self, f: F) -> Coalesce<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> R,
+        R: Try<Ok = Result<Self::Item, (Self::Item, Self::Item)>>,
+        R::Error: From<Self::Error>,
+    {
+        Coalesce::new(self, f)
+    }
+
+    fn chunk_by<F, K, N, B, G>(
         self,
         f: F,
-    ) -> Flatten<Map<Self, FnWrapper<F, Self::Error>>, U::IntoTryIter>
+        init: N,
+        g: G,
+    ) -> ChunkBy<Self, K, FnWrapper<F, Self::Error>, N, G>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> U,
-        U: IntoTryIterator,
-        Self::Error: From<U::Error>,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        self.try_flat_map(FnWrapper::new(f))
+        self.try_chunk_by(FnWrapper::new(f), init, g)
     }
 
-    fn try_flat_map<F, R, U>(self, f: F) -> Flatten<Map<Self, F>, U::IntoTryIter>
+    fn try_chunk_by<F, R, K, N, B, G>(self, f: F, init: N, g: G) -> ChunkBy<Self, K, F, N, G>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
-        R: Try<Ok = U>,
-        U: IntoTryIterator,
-        R::Error: From<Self::Error> + From<U::Error>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: PartialEq,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        self.try_map(f).flatten()
+        ChunkBy::new(self, f, init, g)
     }
 
-    fn flatten<I>(self) -> Flatten<Self, I>
+    #[cfg(feature = "alloc")]
+    fn group_by_key<F, K>(self, f: F) -> GroupByKey<Self, K, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
-        Self::Item: IntoTryIterator<Item = I::Item, Error = I::Error, IntoTryIter = I>,
-        Self::Error: From<I::Error>,
-        I: TryIterator,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
     {
-        Flatten::new(self)
+        self.try_group_by_key(FnWrapper::new(f))
     }
 
-    fn zip<I>(self, other: I) -> Zip<Self, I>
+    #[cfg(feature = "alloc")]
+    fn try_group_by_key<F, R, K>(self, f: F) -> GroupByKey<Self, K, F>
     where
         Self: Sized,
-        I: TryIterator,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = K>,
+        R::Error: From<Self::Error>,
+        K: PartialEq,
     {
-        Zip::new(self, other)
+        ChunkBy::group_by_key(self, f)
     }
 
-    fn chain<I>(self, other: I) -> Chain<Self, I>
+    fn split_fold<F, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<Self, FnWrapper<F, Self::Error>, N, G>
     where
         Self: Sized,
-        I: TryIterator<Item = Self::Item>,
-        Self::Error: From<I::Error>,
+        F: FnMut(&Self::Item) -> bool,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        Chain::new(self, other)
+        self.try_split_fold(FnWrapper::new(f), init, g)
     }
 
-    fn take(self, n: usize) -> Take<Self>
-    where Self: Sized {
-        Take::new(self, n)
+    fn try_split_fold<F, R, N, B, G>(self, f: F, init: N, g: G) -> SplitFold<Self, F, N, G>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
+    {
+        SplitFold::new(self, f, init, g)
     }
 
-    fn take_while<F>(self, f: F) -> TakeWhile<Self, FnWrapper<F, Self::Error>>
+    fn split_inclusive_fold<F, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<Self, FnWrapper<F, Self::Error>, N, G>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> bool,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        self.try_take_while(FnWrapper::new(f))
+        self.try_split_inclusive_fold(FnWrapper::new(f), init, g)
     }
 
-    fn try_take_while<F, R>(self, f: F) -> TakeWhile<Self, F>
+    fn try_split_inclusive_fold<F, R, N, B, G>(
+        self,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<Self, F, N, G>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> R,
         R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        TakeWhile::new(self, f)
+        SplitFold::new(self, f, init, g).inclusive()
     }
 
-    fn map_while<F, T>(self, f: F) -> MapWhile<Self, FnWrapper<F, Self::Error>>
+    fn splitn_fold<F, N, B, G>(
+        self,
+        n: usize,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<Self, FnWrapper<F, Self::Error>, N, G>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> Option<T>,
+        F: FnMut(&Self::Item) -> bool,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        self.try_map_while(FnWrapper::new(f))
+        self.try_splitn_fold(n, FnWrapper::new(f), init, g)
     }
 
-    fn try_map_while<F, R, T>(self, f: F) -> MapWhile<Self, F>
+    fn try_splitn_fold<F, R, N, B, G>(
+        self,
+        n: usize,
+        f: F,
+        init: N,
+        g: G,
+    ) -> SplitFold<Self, F, N, G>
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> R,
-        R: Try<Ok = Option<T>>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
+        N: FnMut() -> B,
+        G: FnMut(B, Self::Item) -> B,
     {
-        MapWhile::new(self, f)
+        SplitFold::new(self, f, init, g).splitn(n)
     }
 
-    fn skip(self, n: usize) -> Skip<Self>
-    where Self: Sized {
-        Skip::new(self, n)
+    #[cfg(feature = "alloc")]
+    fn split<F>(self, f: F) -> Split<Self, FnWrapper<F, Self::Error>>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        self.try_split(FnWrapper::new(f))
     }
 
-    fn skip_while<F>(self, f: F) -> SkipWhile<Self, FnWrapper<F, Self::Error>>
+    #[cfg(feature = "alloc")]
+    fn try_split<F, R>(self, f: F) -> Split<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
+        R::Error: From<Self::Error>,
+    {
+        Split::split(self, f)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn split_inclusive<F>(self, f: F) -> Split<Self, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> bool,
     {
-        self.try_skip_while(FnWrapper::new(f))
+        self.try_split_inclusive(FnWrapper::new(f))
     }
 
-    fn try_skip_while<F, R>(self, f: F) -> SkipWhile<Self, F>
+    #[cfg(feature = "alloc")]
+    fn try_split_inclusive<F, R>(self, f: F) -> Split<Self, F>
     where
         Self: Sized,
         F: FnMut(&Self::Item) -> R,
         R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        SkipWhile::new(self, f)
+        Split::split(self, f).inclusive()
     }
 
-    fn scan<St, F, R, T>(self, state: St, f: F) -> Scan<Self, St, FnWrapper<F, Self::Error>>
+    #[cfg(feature = "alloc")]
+    fn splitn<F>(self, n: usize, f: F) -> Split<Self, FnWrapper<F, Self::Error>>
     where
         Self: Sized,
-        F: FnMut(&mut St, Self::Item) -> Option<T>,
+        F: FnMut(&Self::Item) -> bool,
     {
-        Scan::new(self, state, FnWrapper::new(f))
+        self.try_splitn(n, FnWrapper::new(f))
     }
 
-    fn try_scan<St, F, R, T>(self, state: St, f: F) -> Scan<Self, St, F>
+    #[cfg(feature = "alloc")]
+    fn try_splitn<F, R>(self, n: usize, f: F) -> Split<Self, F>
     where
         Self: Sized,
-        F: FnMut(&mut St, Self::Item) -> R,
-        R: Try<Ok = Option<T>>,
+        F: FnMut(&Self::Item) -> R,
+        R: Try<Ok = bool>,
         R::Error: From<Self::Error>,
     {
-        Scan::new(self, state, f)
+        Split::split(self, f).splitn(n)
+    }
+
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where Self: Sized {
+        ArrayChunks::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where Self: Sized {
+        Chunks::new(self, size)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn chunks_exact(self, size: usize) -> ChunksExact<Self>
+    where Self: Sized {
+        ChunksExact::new(self, size)
+    }
+
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        ArrayWindows::new(self)
+    }
+
+    fn tuple_windows<T>(self) -> TupleWindows<Self, T>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        T: HomogeneousTuple<Item = Self::Item>,
+    {
+        TupleWindows::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn combinations(self, k: usize) -> Combinations<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Combinations::new(self, k)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn combinations_with_replacement(self, k: usize) -> CombinationsWithReplacement<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        CombinationsWithReplacement::new(self, k)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn permutations(self, k: usize) -> Permutations<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Permutations::new(self, k)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn powerset(self) -> Powerset<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Powerset::new(self)
     }
 
     fn cycle(self) -> Cycle<Self>
@@ -873,7 +2159,7 @@ pub trait TryIterator {
         MapErr::new(self, f)
     }
 
-    fn map_err_mut<'a, F, E>(&'a mut self, f: F) -> MapErrMut<'a, Self, F>
+    fn map_err_mut<F, E>(&mut self, f: F) -> MapErrMut<'_, Self, F>
     where
         Self: Sized,
         F: FnMut(Self::Error) -> E,
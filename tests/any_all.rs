@@ -0,0 +1,37 @@
+#![feature(try_trait)]
+
+use try_iterator::{IteratorExt, TryIterator};
+
+fn source(
+    items: Vec<Result<i32, &'static str>>,
+) -> impl TryIterator<Item = i32, Error = &'static str> {
+    items.into_iter().try_map(|x| x)
+}
+
+#[test]
+fn any_and_all_infer_their_closure() {
+    assert_eq!(source(vec![Ok(1), Ok(2)]).any(|x| x == 2), Ok(true));
+    assert_eq!(source(vec![Ok(1), Ok(2)]).any(|x| x == 3), Ok(false));
+    assert_eq!(source(vec![Ok(1), Ok(2)]).all(|x| x > 0), Ok(true));
+    assert_eq!(source(vec![Ok(1), Ok(-2)]).all(|x| x > 0), Ok(false));
+}
+
+#[test]
+fn try_all_is_not_negated() {
+    let positive = |x: i32| Ok::<_, &str>(x > 0);
+    assert_eq!(source(vec![]).try_all(positive), Ok(true));
+    assert_eq!(source(vec![Ok(1), Ok(2)]).try_all(positive), Ok(true));
+    assert_eq!(source(vec![Ok(1), Ok(-2), Ok(3)]).try_all(positive), Ok(false));
+    assert_eq!(source(vec![Ok(1), Err("source")]).try_all(positive), Err("source"));
+    assert_eq!(
+        source(vec![Ok(1), Ok(2)]).try_all(|x| if x == 2 { Err("closure") } else { Ok(true) }),
+        Err("closure")
+    );
+}
+
+#[test]
+fn all_stops_at_the_first_failure() {
+    let mut iter = source(vec![Ok(1), Ok(-2), Ok(3)]);
+    assert_eq!(iter.all(|x| x > 0), Ok(false));
+    assert_eq!(iter.next(), Ok(Some(3)));
+}
@@ -0,0 +1,172 @@
+// the array adaptors keep partial chunks and windows in a buffer of
+// uninitialized memory. these run them over items that count how often they
+// are alive, to catch items that are leaked or dropped twice
+
+use std::rc::Rc;
+use try_iterator::{IteratorExt, TryIterator};
+
+type Item = (i32, Rc<()>);
+
+fn source(
+    counter: &Rc<()>,
+    events: &[Result<i32, &'static str>],
+) -> impl TryIterator<Item = Item, Error = &'static str> + Clone {
+    let events: Vec<_> = events.iter().map(|x| x.map(|x| (x, counter.clone()))).collect();
+    events.into_iter().try_map(|x| x)
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+fn values<const N: usize>(
+    events: &[Result<[Item; N], &'static str>],
+) -> Vec<Result<Vec<i32>, &'static str>> {
+    events
+        .iter()
+        .map(|x| x.as_ref().map(|x| x.iter().map(|y| y.0).collect()).map_err(|&e| e))
+        .collect()
+}
+
+// the number of items that are alive, besides the counter itself
+fn alive(counter: &Rc<()>) -> usize {
+    Rc::strong_count(counter) - 1
+}
+
+#[test]
+fn array_chunks() {
+    let counter = Rc::new(());
+    let events =
+        drain(source(&counter, &[Ok(0), Ok(1), Err("e"), Ok(2), Ok(3), Ok(4)]).array_chunks::<3>());
+    assert_eq!(values(&events), vec![Err("e"), Ok(vec![0, 1, 2])]);
+    assert_eq!(alive(&counter), 3);
+    drop(events);
+    assert_eq!(alive(&counter), 0);
+}
+
+#[test]
+fn array_chunks_remainder() {
+    let counter = Rc::new(());
+    let mut iter = source(&counter, &[Ok(0), Ok(1), Ok(2), Ok(3), Ok(4)]).array_chunks::<2>();
+    assert_eq!(values(&drain(&mut iter)), vec![Ok(vec![0, 1]), Ok(vec![2, 3])]);
+    assert_eq!(iter.remainder().iter().map(|x| x.0).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(alive(&counter), 1);
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+}
+
+// dropping or cloning the adaptor halfway through a chunk
+#[test]
+fn array_chunks_mid_chunk() {
+    let counter = Rc::new(());
+    let mut iter = source(&counter, &[Ok(0), Ok(1), Err("e"), Ok(2)]).array_chunks::<3>();
+    assert_eq!(iter.next().map(|x| x.is_some()), Err("e"));
+    assert_eq!(alive(&counter), 3);
+
+    let clone = iter.clone();
+    assert_eq!(alive(&counter), 6);
+    assert_eq!(values(&drain(clone)), vec![Ok(vec![0, 1, 2])]);
+    assert_eq!(alive(&counter), 3);
+
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+}
+
+#[test]
+fn array_windows() {
+    let counter = Rc::new(());
+    let iter =
+        source(&counter, &[Ok(0), Err("e"), Ok(1), Ok(2), Err("e"), Ok(3)]).array_windows::<2>();
+    let events = drain(iter);
+    assert_eq!(
+        values(&events),
+        vec![Err("e"), Ok(vec![0, 1]), Ok(vec![1, 2]), Err("e"), Ok(vec![2, 3])]
+    );
+    assert_eq!(alive(&counter), 6);
+    drop(events);
+    assert_eq!(alive(&counter), 0);
+}
+
+#[test]
+fn array_windows_mid_window() {
+    let counter = Rc::new(());
+    let mut iter = source(&counter, &[Ok(0), Ok(1), Ok(2), Err("e"), Ok(3)]).array_windows::<3>();
+    assert_eq!(iter.next().map(|x| x.is_some()), Ok(true));
+    assert_eq!(alive(&counter), 3);
+    assert_eq!(iter.next().map(|x| x.is_some()), Err("e"));
+    assert_eq!(alive(&counter), 3);
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+
+    let mut iter = source(&counter, &[Ok(0), Ok(1)]).array_windows::<3>();
+    assert_eq!(iter.next().map(|x| x.is_some()), Ok(false));
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+}
+
+#[test]
+fn tuple_windows() {
+    let counter = Rc::new(());
+    let iter = source(&counter, &[Ok(0), Ok(1), Err("e"), Ok(2)]).tuple_windows::<(_, _, _)>();
+    let events: Vec<_> = drain(iter)
+        .into_iter()
+        .map(|x| x.map(|(a, b, c): (Item, Item, Item)| vec![a.0, b.0, c.0]))
+        .collect();
+    assert_eq!(events, vec![Err("e"), Ok(vec![0, 1, 2])]);
+    assert_eq!(alive(&counter), 0);
+
+    let mut iter = source(&counter, &[Ok(0), Ok(1), Err("e")]).tuple_windows::<(_, _, _)>();
+    assert_eq!(iter.next().map(|x| x.is_some()), Err("e"));
+    assert_eq!(alive(&counter), 2);
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn chunks_exact() {
+    let counter = Rc::new(());
+    let mut iter = source(&counter, &[Ok(0), Err("e"), Ok(1), Ok(2), Ok(3), Ok(4)]).chunks_exact(2);
+    let events: Vec<_> = drain(&mut iter)
+        .into_iter()
+        .map(|x| x.map(|chunk| chunk.iter().map(|y| y.0).collect::<Vec<_>>()))
+        .collect();
+    assert_eq!(events, vec![Err("e"), Ok(vec![0, 1]), Ok(vec![2, 3])]);
+    assert_eq!(iter.remainder().iter().map(|x| x.0).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(alive(&counter), 1);
+    drop(iter);
+    assert_eq!(alive(&counter), 0);
+}
+
+// heap allocated items, which are freed twice if the buffer drops a moved out
+// item again
+#[test]
+fn owned_items() {
+    let source = || {
+        vec![Ok("a"), Ok("b"), Err("e"), Ok("c"), Ok("d"), Ok("e")]
+            .into_iter()
+            .try_map(|x: Result<&str, &str>| x.map(String::from))
+    };
+    assert_eq!(
+        drain(source().array_chunks::<2>()),
+        vec![
+            Ok(["a".to_string(), "b".to_string()]),
+            Err("e"),
+            Ok(["c".to_string(), "d".to_string()])
+        ]
+    );
+    assert_eq!(drain(source().array_windows::<3>()).len(), 4);
+
+    let mut iter = source().array_chunks::<4>();
+    assert_eq!(iter.next(), Err("e"));
+    let _ = iter.clone();
+    drop(iter);
+}
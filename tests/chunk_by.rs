@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+
+use try_iterator::{IteratorExt, TryIterator};
+
+fn source(
+    items: Vec<Result<i32, &'static str>>,
+) -> impl TryIterator<Item = i32, Error = &'static str> {
+    items.into_iter().try_map(|x| x)
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+#[test]
+fn group_by_key() {
+    let iter = source(vec![Ok(1), Ok(1), Ok(2), Ok(3), Ok(3), Ok(1)]).group_by_key(|&x| x);
+    assert_eq!(
+        drain(iter),
+        vec![Ok((1, vec![1, 1])), Ok((2, vec![2])), Ok((3, vec![3, 3])), Ok((1, vec![1]))]
+    );
+}
+
+// the group keeps the items it had before the error
+#[test]
+fn source_errors_keep_the_group() {
+    let iter = source(vec![Ok(1), Err("source"), Ok(1), Ok(2)]).group_by_key(|&x| x);
+    assert_eq!(drain(iter), vec![Err("source"), Ok((1, vec![1, 1])), Ok((2, vec![2]))]);
+}
+
+// the item the key function failed on is gone, and the groups on either side
+// of it stay apart
+#[test]
+fn key_errors_end_the_group() {
+    let key = |&x: &i32| if x == 2 { Err("key") } else { Ok(x) };
+    let iter = source(vec![Ok(1), Ok(1), Ok(2), Ok(1)]).try_group_by_key(key);
+    assert_eq!(drain(iter), vec![Err("key"), Ok((1, vec![1, 1])), Ok((1, vec![1]))]);
+
+    let iter = source(vec![Ok(2), Ok(1)]).try_group_by_key(key);
+    assert_eq!(drain(iter), vec![Err("key"), Ok((1, vec![1]))]);
+}
+
+#[test]
+fn chunk_by_folds_each_group() {
+    let iter =
+        source(vec![Ok(1), Ok(3), Ok(2), Ok(4), Ok(5)]).chunk_by(|x| x % 2, || 0, |acc, x| acc + x);
+    assert_eq!(drain(iter), vec![Ok((1, 4)), Ok((0, 6)), Ok((1, 5))]);
+}
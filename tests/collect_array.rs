@@ -0,0 +1,65 @@
+use try_iterator::{CollectArrayError, IteratorExt, TryIterator};
+
+fn source(
+    events: &[Result<i32, &'static str>],
+) -> impl TryIterator<Item = i32, Error = &'static str> {
+    events.to_vec().into_iter().try_map(|x| x)
+}
+
+#[test]
+fn collect_array() {
+    assert_eq!(source(&[Ok(1), Ok(2), Ok(3)]).collect_array::<3>().ok(), Some([1, 2, 3]));
+    assert_eq!(source(&[]).collect_array::<0>().ok(), Some([]));
+
+    match source(&[Ok(1), Ok(2)]).collect_array::<3>() {
+        Err(CollectArrayError::TooFew(mut partial)) => {
+            assert_eq!(partial.len(), 2);
+            assert_eq!(partial.as_slice(), &[1, 2]);
+            assert_eq!(partial.pop(), Some(2));
+            assert_eq!(partial.as_slice(), &[1]);
+        }
+        result => panic!("expected TooFew, got {:?}", result),
+    }
+
+    match source(&[Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]).collect_array::<3>() {
+        Err(CollectArrayError::TooMany(array, x)) => assert_eq!((array, x), ([1, 2, 3], 4)),
+        result => panic!("expected TooMany, got {:?}", result),
+    }
+
+    match source(&[Ok(1), Err("e"), Ok(2)]).collect_array::<3>() {
+        Err(CollectArrayError::IterError(e)) => assert_eq!(e, "e"),
+        result => panic!("expected IterError, got {:?}", result),
+    }
+
+    // the error is only noticed when the array is already full
+    match source(&[Ok(1), Ok(2), Err("e")]).collect_array::<2>() {
+        Err(CollectArrayError::IterError(e)) => assert_eq!(e, "e"),
+        result => panic!("expected IterError, got {:?}", result),
+    }
+}
+
+// an error drops the items that were read for the array, and a retry starts
+// a new array after it
+#[test]
+fn error_mid_array() {
+    let mut iter = source(&[Ok(1), Err("e"), Ok(2), Ok(3), Ok(4)]);
+    assert_eq!(iter.next_chunk::<3>().map(|x| x.ok()), Err("e"));
+    assert_eq!(iter.next_chunk::<3>().map(|x| x.ok()), Ok(Some([2, 3, 4])));
+    assert_eq!(iter.next_chunk::<3>().map(|x| x.map_err(|x| x.len())), Ok(Err(0)));
+
+    let mut iter = source(&[Ok(1), Err("e"), Ok(2), Ok(3)]);
+    assert!(matches!(iter.by_ref().collect_array::<2>(), Err(CollectArrayError::IterError("e"))));
+    assert_eq!(iter.by_ref().collect_array::<2>().ok(), Some([2, 3]));
+}
+
+#[test]
+fn next_chunk() {
+    let mut iter = source(&[Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]);
+    assert_eq!(iter.next_chunk::<2>().map(|x| x.ok()), Ok(Some([1, 2])));
+    assert_eq!(iter.next_chunk::<0>().map(|x| x.ok()), Ok(Some([])));
+    assert_eq!(iter.next_chunk::<2>().map(|x| x.ok()), Ok(Some([3, 4])));
+    match iter.next_chunk::<2>() {
+        Ok(Err(partial)) => assert_eq!(partial.as_slice(), &[5]),
+        result => panic!("expected the remaining items, got {:?}", result),
+    }
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "alloc")]
+
+use try_iterator::{IteratorExt, TryIterator};
+
+fn source(
+    items: Vec<Result<i32, &'static str>>,
+) -> impl TryIterator<Item = i32, Error = &'static str> + Clone {
+    items.into_iter().try_map(|x| x)
+}
+
+fn items(n: i32) -> impl TryIterator<Item = i32, Error = &'static str> + Clone {
+    source((0..n).map(Ok).collect())
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+fn oks<T>(items: Vec<T>) -> Vec<Result<T, &'static str>> {
+    items.into_iter().map(Ok).collect()
+}
+
+#[test]
+fn combinations() {
+    assert_eq!(
+        drain(items(4).combinations(2)),
+        oks(vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]])
+    );
+    assert_eq!(
+        drain(items(4).combinations(3)),
+        oks(vec![vec![0, 1, 2], vec![0, 1, 3], vec![0, 2, 3], vec![1, 2, 3]])
+    );
+    assert_eq!(drain(items(3).combinations(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(3).combinations(4)), oks(vec![]));
+    assert_eq!(drain(items(0).combinations(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(0).combinations(1)), oks(vec![]));
+}
+
+#[test]
+fn combinations_with_replacement() {
+    assert_eq!(
+        drain(items(3).combinations_with_replacement(2)),
+        oks(vec![vec![0, 0], vec![0, 1], vec![0, 2], vec![1, 1], vec![1, 2], vec![2, 2]])
+    );
+    assert_eq!(drain(items(3).combinations_with_replacement(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(1).combinations_with_replacement(3)), oks(vec![vec![0, 0, 0]]));
+    assert_eq!(drain(items(0).combinations_with_replacement(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(0).combinations_with_replacement(2)), oks(vec![]));
+}
+
+#[test]
+fn permutations() {
+    assert_eq!(
+        drain(items(3).permutations(2)),
+        oks(vec![vec![0, 1], vec![0, 2], vec![1, 0], vec![1, 2], vec![2, 0], vec![2, 1]])
+    );
+    assert_eq!(
+        drain(items(3).permutations(3)),
+        oks(vec![
+            vec![0, 1, 2],
+            vec![0, 2, 1],
+            vec![1, 0, 2],
+            vec![1, 2, 0],
+            vec![2, 0, 1],
+            vec![2, 1, 0],
+        ])
+    );
+    assert_eq!(drain(items(3).permutations(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(2).permutations(3)), oks(vec![]));
+    assert_eq!(drain(items(0).permutations(0)), oks(vec![vec![]]));
+    assert_eq!(drain(items(0).permutations(1)), oks(vec![]));
+}
+
+#[test]
+fn powerset() {
+    assert_eq!(
+        drain(items(3).powerset()),
+        oks(vec![
+            vec![],
+            vec![0],
+            vec![1],
+            vec![2],
+            vec![0, 1],
+            vec![0, 2],
+            vec![1, 2],
+            vec![0, 1, 2],
+        ])
+    );
+    assert_eq!(drain(items(0).powerset()), oks(vec![vec![]]));
+}
+
+#[test]
+fn cartesian_product() {
+    assert_eq!(
+        drain(items(2).cartesian_product(items(3))),
+        oks(vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)])
+    );
+    assert_eq!(drain(items(0).cartesian_product(items(3))), oks(vec![]));
+    assert_eq!(drain(items(2).cartesian_product(items(0))), oks(vec![]));
+}
+
+// any item that hasn't been read yet can turn out to be an error, which
+// takes away all the combinations it would have been part of. so the lower
+// bounds are the fewest combinations and errors that could be left
+#[test]
+fn size_hints() {
+    assert_eq!(items(5).combinations(2).size_hint(), (4, Some(10)));
+    assert_eq!(items(5).combinations(6).size_hint(), (0, Some(0)));
+    assert_eq!(items(5).combinations(0).size_hint(), (1, Some(1)));
+    assert_eq!(items(4).combinations_with_replacement(2).size_hint(), (4, Some(10)));
+    assert_eq!(items(4).permutations(2).size_hint(), (3, Some(12)));
+    assert_eq!(items(4).permutations(5).size_hint(), (0, Some(0)));
+    assert_eq!(items(4).powerset().size_hint(), (5, Some(16)));
+    assert_eq!(items(4).cartesian_product(items(3)).size_hint(), (4, Some(12)));
+
+    let mut iter = items(5).combinations(2);
+    for _ in 0..3 {
+        iter.next().unwrap();
+    }
+    assert_eq!(iter.size_hint(), (4, Some(7)));
+    drain(&mut iter);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    // all items are read before the first permutation
+    let mut iter = items(4).permutations(2);
+    for _ in 0..5 {
+        iter.next().unwrap();
+    }
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+}
+
+// an error while the items are being read in doesn't lose the items that
+// were already read, and reading resumes after it
+#[test]
+fn errors_while_filling_the_buffer() {
+    let source = || source(vec![Ok(0), Ok(1), Err("e"), Ok(2)]);
+    assert_eq!(
+        drain(source().combinations(2)),
+        vec![Ok(vec![0, 1]), Err("e"), Ok(vec![0, 2]), Ok(vec![1, 2])]
+    );
+    assert_eq!(
+        drain(source().combinations_with_replacement(2)),
+        vec![
+            Ok(vec![0, 0]),
+            Ok(vec![0, 1]),
+            Err("e"),
+            Ok(vec![0, 2]),
+            Ok(vec![1, 1]),
+            Ok(vec![1, 2]),
+            Ok(vec![2, 2])
+        ]
+    );
+    assert_eq!(
+        drain(source().powerset()),
+        vec![
+            Ok(vec![]),
+            Ok(vec![0]),
+            Ok(vec![1]),
+            Err("e"),
+            Ok(vec![2]),
+            Ok(vec![0, 1]),
+            Ok(vec![0, 2]),
+            Ok(vec![1, 2]),
+            Ok(vec![0, 1, 2])
+        ]
+    );
+    assert_eq!(
+        drain(source().cartesian_product(items(2))),
+        vec![Ok((0, 0)), Ok((0, 1)), Ok((1, 0)), Ok((1, 1)), Err("e"), Ok((2, 0)), Ok((2, 1))]
+    );
+}
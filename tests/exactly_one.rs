@@ -0,0 +1,65 @@
+use try_iterator::{AtMostOneError, ExactlyOneError, IteratorExt, TooMany, TryIterator};
+
+fn source(
+    events: &[Result<i32, &'static str>],
+) -> impl TryIterator<Item = i32, Error = &'static str> {
+    events.to_vec().into_iter().try_map(|x| x)
+}
+
+#[test]
+fn exactly_one() {
+    assert!(matches!(source(&[Ok(1)]).exactly_one(), Ok(1)));
+    assert!(matches!(source(&[]).exactly_one(), Err(ExactlyOneError::Empty)));
+    assert!(matches!(source(&[Err("e")]).exactly_one(), Err(ExactlyOneError::IterError("e"))));
+    assert!(matches!(
+        source(&[Ok(1), Err("e")]).exactly_one(),
+        Err(ExactlyOneError::IterError("e"))
+    ));
+
+    match source(&[Ok(1), Ok(2), Ok(3), Ok(4)]).exactly_one() {
+        Err(ExactlyOneError::TooMany(TooMany { first, second, mut rest })) => {
+            assert_eq!((first, second), (1, 2));
+            assert_eq!(rest.next(), Ok(Some(3)));
+        }
+        _ => panic!("expected TooMany"),
+    }
+}
+
+#[test]
+fn at_most_one() {
+    assert!(matches!(source(&[]).at_most_one(), Ok(None)));
+    assert!(matches!(source(&[Ok(1)]).at_most_one(), Ok(Some(1))));
+    assert!(matches!(
+        source(&[Err("e"), Ok(1)]).at_most_one(),
+        Err(AtMostOneError::IterError("e"))
+    ));
+
+    // the first item is lost along with the iterator
+    assert!(matches!(
+        source(&[Ok(1), Err("e")]).at_most_one(),
+        Err(AtMostOneError::IterError("e"))
+    ));
+
+    match source(&[Ok(1), Ok(2), Err("e"), Ok(4)]).at_most_one() {
+        Err(AtMostOneError::TooMany(TooMany { first, second, mut rest })) => {
+            assert_eq!((first, second), (1, 2));
+            assert_eq!(rest.next(), Err("e"));
+            assert_eq!(rest.next(), Ok(Some(4)));
+            assert_eq!(rest.next(), Ok(None));
+        }
+        _ => panic!("expected TooMany"),
+    }
+}
+
+#[test]
+fn single() {
+    assert_eq!(source(&[]).single(), Ok(None));
+    assert_eq!(source(&[Ok(1)]).single(), Ok(Some(1)));
+    assert_eq!(source(&[Ok(1), Ok(2)]).single(), Ok(None));
+    assert_eq!(source(&[Ok(1), Ok(2), Ok(3)]).single(), Ok(None));
+    assert_eq!(source(&[Err("e"), Ok(1)]).single(), Err("e"));
+    assert_eq!(source(&[Ok(1), Err("e")]).single(), Err("e"));
+
+    // the items after the second aren't read
+    assert_eq!(source(&[Ok(1), Ok(2), Err("e")]).single(), Ok(None));
+}
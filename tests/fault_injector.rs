@@ -0,0 +1,73 @@
+#![cfg(feature = "testing")]
+
+use try_iterator::{testing::FaultInjector, IteratorExt, TryIterator};
+
+fn items(n: i32) -> impl TryIterator<Item = i32, Error = &'static str> + Clone {
+    (0..n).map(Ok).collect::<Vec<_>>().into_iter().try_map(|x| x)
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+// the indices of the events that are errors
+fn faults<T, E>(events: &[Result<T, E>]) -> Vec<usize> {
+    events.iter().enumerate().filter(|(_, x)| x.is_err()).map(|(i, _)| i).collect()
+}
+
+#[test]
+fn at_indices() {
+    let events = drain(FaultInjector::at_indices(items(6), "fault", vec![4, 0, 2, 4, 9]));
+    assert_eq!(events, vec![Err("fault"), Ok(1), Err("fault"), Ok(3), Err("fault"), Ok(5)]);
+
+    let events = drain(FaultInjector::at_indices(items(3), "fault", None));
+    assert_eq!(events, vec![Ok(0), Ok(1), Ok(2)]);
+
+    let iter = FaultInjector::at_indices(items(6), "fault", vec![1, 3]);
+    assert_eq!(iter.size_hint(), (4, Some(6)));
+}
+
+#[test]
+fn random() {
+    let events = drain(FaultInjector::random(items(200), "fault", 7, 4));
+    assert_eq!(events.len(), 200);
+    let positions = faults(&events);
+    assert!(!positions.is_empty() && positions.len() < 200);
+    for (i, event) in events.iter().enumerate() {
+        if !positions.contains(&i) {
+            assert_eq!(*event, Ok(i as i32));
+        }
+    }
+
+    // the same seed gives the same positions, another seed other ones
+    assert_eq!(faults(&drain(FaultInjector::random(items(200), "fault", 7, 4))), positions);
+    assert_ne!(faults(&drain(FaultInjector::random(items(200), "fault", 8, 4))), positions);
+
+    assert_eq!(
+        faults(&drain(FaultInjector::random(items(10), "fault", 7, 1))),
+        (0..10).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn every_nth_call() {
+    let events = drain(FaultInjector::every_nth_call(items(4), "fault", 2));
+    assert_eq!(
+        events,
+        vec![Ok(0), Err("fault"), Ok(1), Err("fault"), Ok(2), Err("fault"), Ok(3), Err("fault")]
+    );
+}
+
+#[test]
+#[should_panic]
+fn every_call() {
+    let _ = FaultInjector::every_nth_call(items(4), "fault", 1);
+}
@@ -0,0 +1,90 @@
+use try_iterator::{
+    forward_count, forward_last, forward_nth, forward_nth_back, forward_size_hint,
+    DoubleEndedTryIterator, IteratorExt, TryIterator,
+};
+
+// counts the calls to `next` and `next_back`, to tell whether skipping went
+// through them
+struct Counted<I> {
+    iter: I,
+    calls: usize,
+}
+
+impl<I> TryIterator for Counted<I>
+where I: TryIterator
+{
+    type Item = I::Item;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.calls += 1;
+        self.iter.next()
+    }
+
+    forward_size_hint!(iter);
+    forward_nth!(iter);
+    forward_count!(iter);
+    forward_last!(iter);
+}
+
+impl<I> DoubleEndedTryIterator for Counted<I>
+where I: DoubleEndedTryIterator
+{
+    fn next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.calls += 1;
+        self.iter.next_back()
+    }
+
+    forward_nth_back!(iter);
+}
+
+// a tuple struct, to check that fields can be tuple indices
+struct Doubled<I>(I);
+
+impl<I> TryIterator for Doubled<I>
+where I: TryIterator<Item = i32>
+{
+    type Item = i32;
+    type Error = I::Error;
+
+    fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.0.next()?.map(|x| x * 2))
+    }
+
+    forward_nth!(0, |x| x * 2);
+    forward_last!(0, |x| x * 2);
+}
+
+fn counted(len: i32) -> Counted<impl DoubleEndedTryIterator<Item = i32, Error = ()>> {
+    Counted { iter: (0..len).try_map(Ok), calls: 0 }
+}
+
+#[test]
+fn nth_skips_without_calling_next() {
+    let mut iter = counted(10);
+    assert_eq!(iter.nth(3), Ok(Some(3)));
+    assert_eq!(iter.try_nth(10), Ok(Err(4)));
+    assert_eq!(iter.calls, 0);
+
+    let mut iter = counted(10);
+    assert_eq!(iter.nth_back(3), Ok(Some(6)));
+    assert_eq!(iter.try_nth_back(1), Ok(Ok(4)));
+    assert_eq!(iter.try_nth_back(10), Ok(Err(6)));
+    assert_eq!(iter.calls, 0);
+}
+
+#[test]
+fn size_hint_count_and_last_are_forwarded() {
+    let iter = counted(5);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(counted(5).count(), Ok(5));
+    assert_eq!(counted(5).last(), Ok(Some(4)));
+}
+
+#[test]
+fn forwarded_items_are_mapped() {
+    let mut iter = Doubled((0..10).try_map(Ok::<_, ()>));
+    assert_eq!(iter.nth(2), Ok(Some(4)));
+    assert_eq!(iter.next(), Ok(Some(6)));
+    assert_eq!(Doubled((0..10).try_map(Ok::<_, ()>)).last(), Ok(Some(18)));
+}
@@ -0,0 +1,108 @@
+use try_iterator::{EitherOrBoth, IteratorExt, TryIterator};
+
+use EitherOrBoth::{Both, Left, Right};
+
+type Row = (i32, char);
+
+fn source(
+    rows: Vec<Result<Row, &'static str>>,
+) -> impl TryIterator<Item = Row, Error = &'static str> {
+    rows.into_iter().try_map(|x| x)
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+#[test]
+fn merge_join_by() {
+    let left = source(vec![Ok((1, 'a')), Ok((2, 'b')), Ok((4, 'c'))]);
+    let right = source(vec![Ok((2, 'x')), Ok((3, 'y')), Ok((4, 'z')), Ok((5, 'w'))]);
+    assert_eq!(
+        drain(left.merge_join_by(right, |x, y| x.0.cmp(&y.0))),
+        vec![
+            Ok(Left((1, 'a'))),
+            Ok(Both((2, 'b'), (2, 'x'))),
+            Ok(Right((3, 'y'))),
+            Ok(Both((4, 'c'), (4, 'z'))),
+            Ok(Right((5, 'w'))),
+        ]
+    );
+}
+
+#[test]
+fn merge_join_by_resumes_after_errors() {
+    let left = source(vec![Ok((1, 'a')), Err("left"), Ok((3, 'c'))]);
+    let right = source(vec![Ok((1, 'x')), Ok((2, 'y')), Err("right"), Ok((3, 'z'))]);
+    assert_eq!(
+        drain(left.merge_join_by(right, |x, y| x.0.cmp(&y.0))),
+        vec![
+            Ok(Both((1, 'a'), (1, 'x'))),
+            Err("left"),
+            Ok(Right((2, 'y'))),
+            Err("right"),
+            Ok(Both((3, 'c'), (3, 'z'))),
+        ]
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_join() {
+    let left = source(vec![Ok((1, 'a')), Ok((2, 'b')), Ok((1, 'c')), Ok((9, 'd'))]);
+    let right = source(vec![Ok((5, 'p')), Ok((1, 'q')), Ok((4, 'r')), Ok((1, 's')), Ok((2, 't'))]);
+    assert_eq!(
+        drain(left.hash_join(right, |x| x.0, |y| y.0)),
+        vec![
+            Ok(Both((1, 'a'), (1, 'q'))),
+            Ok(Both((1, 'a'), (1, 's'))),
+            Ok(Both((2, 'b'), (2, 't'))),
+            Ok(Both((1, 'c'), (1, 'q'))),
+            Ok(Both((1, 'c'), (1, 's'))),
+            Ok(Left((9, 'd'))),
+            Ok(Right((5, 'p'))),
+            Ok(Right((4, 'r'))),
+        ]
+    );
+}
+
+// unmatched rows come out in the order they were read, not in the order of
+// the map
+#[cfg(feature = "std")]
+#[test]
+fn hash_join_yields_unmatched_rows_in_order() {
+    let right: Vec<_> = (0..100).rev().map(|x| Ok((x, 'r'))).collect();
+    let unmatched: Vec<_> =
+        (0..100).rev().filter(|x| x % 10 != 0).map(|x| Ok(Right((x, 'r')))).collect();
+    let left = source((0..100).step_by(10).map(|x| Ok((x, 'l'))).collect());
+    let events = drain(left.hash_join(source(right), |x| x.0, |y| y.0));
+    assert_eq!(events[10..], unmatched[..]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn hash_join_in_a_btree_map() {
+    use std::collections::BTreeMap;
+
+    let left = source(vec![Ok((3, 'a')), Ok((1, 'b'))]);
+    let right = source(vec![Ok((2, 'x')), Ok((1, 'y')), Err("right"), Ok((0, 'z'))]);
+    let join = left.hash_join_in::<_, _, _, _, BTreeMap<_, _>>(right, |x| x.0, |y| y.0);
+    assert_eq!(
+        drain(join),
+        vec![
+            Err("right"),
+            Ok(Left((3, 'a'))),
+            Ok(Both((1, 'b'), (1, 'y'))),
+            Ok(Right((2, 'x'))),
+            Ok(Right((0, 'z'))),
+        ]
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "proptest")]
+
+use proptest_crate::proptest;
+use try_iterator::{
+    testing::{check_model, script, wild_script},
+    TryIterator,
+};
+
+fn halve(x: i32) -> Result<i32, u8> {
+    if x % 2 == 0 { Ok(x / 2) } else { Err(x as u8) }
+}
+
+proptest! {
+    #[test]
+    fn try_map(script in script(0..100i32, 0..3u8, 0..20)) {
+        check_model(&script, |iter| iter.try_map(halve), |events| {
+            events.into_iter().map(|x| x.and_then(halve)).collect()
+        });
+    }
+
+    #[test]
+    fn filter(script in script(0..100i32, 0..3u8, 0..20)) {
+        check_model(&script, |iter| iter.filter(|x| x % 3 != 0), |mut events| {
+            events.retain(|x| x.as_ref().map_or(true, |x| x % 3 != 0));
+            events
+        });
+    }
+
+    #[test]
+    fn peekable(script in wild_script(0..100i32, 0..3u8, 0..20)) {
+        check_model(&script, |iter| iter.peekable(), |events| events);
+    }
+
+    #[test]
+    fn chain(
+        first in wild_script(0..100i32, 0..3u8, 0..10),
+        second in script(0..100i32, 0..3u8, 0..10),
+    ) {
+        let second_events = second.events();
+        check_model(&first, |iter| iter.chain(second.iter()), |mut events| {
+            events.extend(second_events);
+            events
+        });
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "testing")]
+
+use std::collections::BTreeMap;
+
+use try_iterator::{
+    testing::{check_double_ended, check_try_iterator, FaultInjector},
+    DoubleEndedTryIterator, ExactSizeTryIterator, IteratorExt, TryIterator,
+};
+
+// the items of `events`, where an error takes the place of an item
+fn source(
+    events: &[Result<i32, &'static str>],
+) -> impl DoubleEndedTryIterator<Item = i32, Error = &'static str> + ExactSizeTryIterator + Clone {
+    events.to_vec().into_iter().try_map(|x| x)
+}
+
+// the items of `events`, and an error that comes on top of them every third
+// call to `next`
+fn transient(
+    events: &[Result<i32, &'static str>],
+) -> impl TryIterator<Item = i32, Error = &'static str> + Clone {
+    FaultInjector::every_nth_call(source(events), "transient", 3)
+}
+
+fn items(n: i32) -> Vec<Result<i32, &'static str>> {
+    (0..n).map(Ok).collect()
+}
+
+fn with_errors(n: i32) -> Vec<Result<i32, &'static str>> {
+    (0..n).map(|x| if x % 4 == 2 { Err("error") } else { Ok(x) }).collect()
+}
+
+fn sources() -> Vec<Vec<Result<i32, &'static str>>> {
+    vec![items(0), items(1), items(7), with_errors(3), with_errors(11)]
+}
+
+#[test]
+fn try_map() {
+    for events in sources() {
+        check_double_ended(|| {
+            source(&events).try_map(|x| if x % 3 == 1 { Err("map") } else { Ok(x) })
+        });
+        check_try_iterator(|| {
+            transient(&events).try_map(|x| if x % 3 == 1 { Err("map") } else { Ok(x) })
+        });
+    }
+}
+
+#[test]
+fn peekable() {
+    for events in sources() {
+        check_double_ended(|| source(&events).peekable());
+        check_try_iterator(|| transient(&events).peekable());
+
+        // with an item that was already peeked at
+        check_double_ended(|| {
+            let mut iter = source(&events).peekable();
+            let _ = iter.peek();
+            iter
+        });
+    }
+}
+
+#[test]
+fn chain() {
+    for events in sources() {
+        for other in sources() {
+            check_double_ended(|| source(&events).chain(source(&other)));
+            check_try_iterator(|| transient(&events).chain(transient(&other)));
+        }
+    }
+}
+
+#[test]
+fn flatten() {
+    for events in sources() {
+        check_double_ended(|| source(&events).map(|n| source(&with_errors(n))).flatten());
+        check_try_iterator(|| transient(&events).map(|n| transient(&with_errors(n))).flatten());
+    }
+}
+
+#[test]
+fn skip() {
+    for events in sources() {
+        for n in 0..5 {
+            check_double_ended(|| source(&events).skip(n));
+            check_try_iterator(|| transient(&events).skip(n));
+        }
+    }
+}
+
+#[test]
+fn take() {
+    for events in sources() {
+        for n in 0..5 {
+            check_double_ended(|| source(&events).take(n));
+            check_try_iterator(|| transient(&events).take(n));
+        }
+    }
+}
+
+#[test]
+fn expect_len() {
+    for events in sources() {
+        for n in [0, 3, 7, 11].iter().copied() {
+            check_try_iterator(|| source(&events).expect_len(n));
+            check_try_iterator(|| transient(&events).expect_len(n));
+        }
+    }
+    assert_eq!(source(&items(3)).expect_len(5).size_hint(), (3, Some(3)));
+    assert_eq!(source(&items(7)).expect_len(5).size_hint(), (5, Some(5)));
+}
+
+#[test]
+fn at_least() {
+    for events in sources() {
+        for n in [0, 3, 7, 11].iter().copied() {
+            check_try_iterator(|| source(&events).at_least(n));
+            check_try_iterator(|| transient(&events).at_least(n));
+        }
+    }
+    assert_eq!(source(&items(3)).at_least(5).size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn at_most() {
+    for events in sources() {
+        for n in [0, 3, 7, 11].iter().copied() {
+            check_try_iterator(|| source(&events).at_most(n));
+            check_try_iterator(|| transient(&events).at_most(n));
+        }
+    }
+    assert_eq!(source(&items(7)).at_most(5).size_hint(), (5, Some(5)));
+}
+
+#[test]
+fn array_chunks() {
+    for events in sources() {
+        check_try_iterator(|| source(&events).array_chunks::<1>());
+        check_try_iterator(|| source(&events).array_chunks::<3>());
+        check_try_iterator(|| transient(&events).array_chunks::<3>());
+        check_try_iterator(|| source(&events).chunks_exact(3));
+        check_try_iterator(|| transient(&events).chunks_exact(3));
+    }
+}
+
+#[test]
+fn array_windows() {
+    for events in sources() {
+        check_try_iterator(|| source(&events).array_windows::<1>());
+        check_try_iterator(|| source(&events).array_windows::<3>());
+        check_try_iterator(|| transient(&events).array_windows::<3>());
+        check_try_iterator(|| source(&events).tuple_windows::<(_, _, _)>());
+        check_try_iterator(|| transient(&events).tuple_windows::<(_, _, _)>());
+    }
+}
+
+#[test]
+fn combinatorics() {
+    for events in sources() {
+        for k in 0..4 {
+            check_try_iterator(|| source(&events).combinations(k));
+            check_try_iterator(|| transient(&events).combinations(k));
+            check_try_iterator(|| source(&events).combinations_with_replacement(k));
+            check_try_iterator(|| transient(&events).combinations_with_replacement(k));
+            check_try_iterator(|| source(&events).permutations(k));
+            check_try_iterator(|| transient(&events).permutations(k));
+        }
+        check_try_iterator(|| source(&events).powerset());
+        check_try_iterator(|| transient(&events).powerset());
+        for other in sources() {
+            check_try_iterator(|| source(&events).cartesian_product(source(&other)));
+            check_try_iterator(|| transient(&events).cartesian_product(transient(&other)));
+        }
+    }
+}
+
+#[test]
+fn duplicates() {
+    let mut all = sources();
+    all.push(vec![Ok(1), Ok(2), Ok(3), Ok(1), Ok(2), Ok(3)]);
+    all.push(vec![Ok(1), Ok(1), Err("error"), Ok(1), Ok(2), Err("error"), Ok(2)]);
+    for events in all {
+        check_try_iterator(|| source(&events).duplicates::<BTreeMap<_, _>>());
+        check_try_iterator(|| transient(&events).duplicates::<BTreeMap<_, _>>());
+        check_try_iterator(|| source(&events).duplicates_by::<BTreeMap<_, _>, _, _>(|x| x % 2));
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use std::collections::{HashMap, HashSet};
+use try_iterator::{IteratorExt, TryIterator};
+
+fn source(
+    events: &[Result<i32, &'static str>],
+) -> impl TryIterator<Item = i32, Error = &'static str> {
+    events.to_vec().into_iter().try_map(|x| x)
+}
+
+fn drain<I>(mut iter: I) -> Vec<Result<I::Item, I::Error>>
+where I: TryIterator {
+    let mut events = Vec::new();
+    loop {
+        match iter.next() {
+            Ok(None) => return events,
+            Ok(Some(x)) => events.push(Ok(x)),
+            Err(e) => events.push(Err(e)),
+        }
+    }
+}
+
+#[test]
+fn unique() {
+    let iter = source(&[Ok(1), Ok(2), Ok(1), Err("e"), Ok(3), Ok(2)]).unique::<HashSet<_>>();
+    assert_eq!(drain(iter), vec![Ok(1), Ok(2), Err("e"), Ok(3)]);
+}
+
+#[test]
+fn duplicates() {
+    let iter =
+        source(&[Ok(1), Ok(2), Ok(1), Err("e"), Ok(1), Ok(2), Ok(3)]).duplicates::<HashMap<_, _>>();
+    assert_eq!(drain(iter), vec![Ok(1), Err("e"), Ok(2)]);
+}
+
+// keys that were seen once can each be completed by a single item
+#[test]
+fn duplicates_size_hint() {
+    let mut iter =
+        source(&[Ok(1), Ok(2), Ok(3), Ok(1), Ok(2), Ok(3)]).duplicates::<HashMap<_, _>>();
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    assert_eq!(iter.next(), Ok(Some(1)));
+    assert_eq!(iter.size_hint(), (0, Some(2)));
+    assert_eq!(iter.next(), Ok(Some(2)));
+    assert_eq!(iter.size_hint(), (0, Some(1)));
+    assert_eq!(iter.next(), Ok(Some(3)));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), Ok(None));
+
+    let mut iter = source(&[Ok(1), Ok(2), Ok(3), Ok(4), Ok(1)]).duplicates::<HashMap<_, _>>();
+    assert_eq!(iter.next(), Ok(Some(1)));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}